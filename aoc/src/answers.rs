@@ -0,0 +1,112 @@
+use crate::puzzles::Part;
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The known answers to both parts of a puzzle for one input file. A part is left out when the
+/// input doesn't apply to it.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Answers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub a: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub b: Option<String>,
+}
+
+impl Answers {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::A => self.a.as_deref(),
+            Part::B => self.b.as_deref(),
+        }
+    }
+}
+
+/// The answers that have been confirmed for each day, keyed by the day's directory and then by
+/// the name of the input file within it.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Registry(BTreeMap<String, BTreeMap<String, Answers>>);
+
+impl Registry {
+    /// The registry that is checked in at the root of the workspace.
+    pub fn default_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("answers.json")
+    }
+
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let s = fs::read_to_string(path)
+            .with_context(|| format!("unable to read answers file {}", path.display()))?;
+        serde_json::from_str(&s)
+            .with_context(|| format!("unable to parse answers file {}", path.display()))
+    }
+
+    /// The input files with known answers for a day, in order of their names.
+    pub fn inputs(&self, directory: &str) -> impl Iterator<Item = (&str, &Answers)> {
+        self.0
+            .get(directory)
+            .into_iter()
+            .flatten()
+            .map(|(name, answers)| (name.as_str(), answers))
+    }
+}
+
+impl FromIterator<(String, String, Answers)> for Registry {
+    fn from_iter<I: IntoIterator<Item = (String, String, Answers)>>(iter: I) -> Self {
+        let mut registry: Self = Default::default();
+
+        for (directory, name, answers) in iter {
+            registry
+                .0
+                .entry(directory)
+                .or_default()
+                .insert(name, answers);
+        }
+
+        registry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzles::PUZZLES;
+
+    #[test]
+    fn test_load() {
+        let registry = Registry::load(&Registry::default_path()).unwrap();
+
+        // Every puzzle should have an answer for both parts of its real input, and every input
+        // that's listed should exist.
+        for puzzle in PUZZLES.iter() {
+            let mut inputs = registry.inputs(puzzle.directory).peekable();
+            assert!(
+                inputs.peek().is_some(),
+                "no answers for {}",
+                puzzle.directory
+            );
+
+            for (name, answers) in inputs {
+                assert!(puzzle.input_path(name).exists());
+                if name == "input" {
+                    assert!(answers.a.is_some() && answers.b.is_some());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let registry: Registry =
+            serde_json::from_str(r#"{"day01": {"input": {"a": "7"}}}"#).unwrap();
+        let inputs: Vec<_> = registry.inputs("day01").collect();
+        assert_eq!(inputs.len(), 1);
+        assert_eq!(inputs[0].0, "input");
+        assert_eq!(inputs[0].1.get(Part::A), Some("7"));
+        assert_eq!(inputs[0].1.get(Part::B), None);
+        assert_eq!(registry.inputs("day02").count(), 0);
+    }
+}
@@ -0,0 +1,334 @@
+use crate::puzzles::Part;
+use anyhow::Context;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use solution::{ParseError, Solution};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Solve,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 2] = [Phase::Parse, Phase::Solve];
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Solve => write!(f, "solve"),
+        }
+    }
+}
+
+/// How long each iteration of each phase took.
+#[derive(Debug, Default, Clone)]
+pub struct Samples {
+    pub parse: Vec<Duration>,
+    pub solve: Vec<Duration>,
+}
+
+/// Parses the input `iterations` times, and then solves the part that many times using the last
+/// parsed input, so the two phases are timed independently.
+pub fn measure<S: Solution>(
+    input: &str,
+    part: Part,
+    iterations: usize,
+) -> Result<Samples, ParseError> {
+    let mut samples: Samples = Default::default();
+    let mut parsed = None;
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let result = S::parse(black_box(input))?;
+        samples.parse.push(start.elapsed());
+
+        // Replacing the previous input drops it, which happens outside of the timing.
+        parsed = Some(result);
+    }
+
+    let parsed = match parsed {
+        Some(parsed) => parsed,
+        None => return Ok(samples),
+    };
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        match part {
+            Part::A => drop(black_box(S::solve_part_a(black_box(&parsed)))),
+            Part::B => drop(black_box(S::solve_part_b(black_box(&parsed)))),
+        }
+        samples.solve.push(start.elapsed());
+    }
+
+    Ok(samples)
+}
+
+// Durations are saved as a whole number of nanoseconds, which is easier to read in a baseline
+// file than serde's default pair of seconds and nanoseconds.
+mod nanos {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Summary {
+    #[serde(rename = "min_ns", with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "max_ns", with = "nanos")]
+    pub max: Duration,
+}
+
+impl Summary {
+    pub fn new(samples: &[Duration]) -> Self {
+        let mut samples = samples.to_vec();
+        samples.sort_unstable();
+
+        if samples.is_empty() {
+            return Default::default();
+        }
+
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Self {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Benchmark {
+    pub parse: Summary,
+    pub solve: Summary,
+}
+
+impl Benchmark {
+    pub fn get(&self, phase: Phase) -> &Summary {
+        match phase {
+            Phase::Parse => &self.parse,
+            Phase::Solve => &self.solve,
+        }
+    }
+}
+
+impl From<&Samples> for Benchmark {
+    fn from(samples: &Samples) -> Self {
+        Self {
+            parse: Summary::new(&samples.parse),
+            solve: Summary::new(&samples.solve),
+        }
+    }
+}
+
+/// Saved benchmarks to compare later runs against, keyed by the day's directory and then by the
+/// part.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Baseline(BTreeMap<String, BTreeMap<String, Benchmark>>);
+
+impl Baseline {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let s = fs::read_to_string(path)
+            .with_context(|| format!("unable to read baseline file {}", path.display()))?;
+        serde_json::from_str(&s)
+            .with_context(|| format!("unable to parse baseline file {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let s = serde_json::to_string_pretty(self)?;
+        fs::write(path, s + "\n")
+            .with_context(|| format!("unable to write baseline file {}", path.display()))
+    }
+
+    pub fn get(&self, directory: &str, part: Part) -> Option<&Benchmark> {
+        self.0.get(directory)?.get(&part.to_string())
+    }
+
+    pub fn insert(&mut self, directory: &str, part: Part, benchmark: Benchmark) {
+        self.0
+            .entry(directory.to_string())
+            .or_default()
+            .insert(part.to_string(), benchmark);
+    }
+}
+
+/// The benchmark of one part of a puzzle.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub day: u8,
+    pub directory: &'static str,
+    pub part: Part,
+    pub benchmark: Benchmark,
+}
+
+/// How much slower `current` is than `baseline`, as a percentage. It's negative if it's faster.
+pub fn change(baseline: Duration, current: Duration) -> f64 {
+    let baseline = baseline.as_nanos() as f64;
+
+    if baseline == 0.0 {
+        return 0.0;
+    }
+
+    (current.as_nanos() as f64 - baseline) / baseline * 100.0
+}
+
+/// The phases of the runs whose median got slower than the baseline by more than `threshold`
+/// percent. Runs that aren't in the baseline can't regress.
+pub fn regressions<'a>(
+    runs: &'a [Run],
+    baseline: &'a Baseline,
+    threshold: f64,
+) -> impl Iterator<Item = (&'a Run, Phase)> + 'a {
+    runs.iter()
+        .flat_map(|run| Phase::ALL.map(|phase| (run, phase)))
+        .filter(move |&(run, phase)| {
+            baseline
+                .get(run.directory, run.part)
+                .map(|saved| saved.get(phase).median)
+                .is_some_and(|saved| change(saved, run.benchmark.get(phase).median) > threshold)
+        })
+}
+
+/// Formats the runs as a table, with a column comparing each median to the baseline, if given.
+pub fn report(runs: &[Run], baseline: Option<&Baseline>, threshold: f64) -> String {
+    let mut table = format!(
+        "{:>3}  {:4}  {:5}  {:>10}  {:>10}  {:>10}",
+        "day", "part", "phase", "min", "median", "max"
+    );
+
+    if baseline.is_some() {
+        table += &format!("  {:>10}  {:>8}", "baseline", "change");
+    }
+
+    table += "\n";
+
+    for run in runs {
+        for phase in Phase::ALL {
+            let summary = run.benchmark.get(phase);
+
+            table += &format!(
+                "{:>3}  {:4}  {:5}  {:>10}  {:>10}  {:>10}",
+                run.day,
+                run.part.to_string(),
+                phase.to_string(),
+                format!("{:.1?}", summary.min),
+                format!("{:.1?}", summary.median),
+                format!("{:.1?}", summary.max),
+            );
+
+            if let Some(baseline) = baseline {
+                match baseline.get(run.directory, run.part) {
+                    Some(saved) => {
+                        let saved = saved.get(phase).median;
+                        let change = change(saved, summary.median);
+                        table += &format!("  {:>10}  {:>+7.1}%", format!("{:.1?}", saved), change);
+                        if change > threshold {
+                            table += "  regressed";
+                        }
+                    }
+                    None => table += &format!("  {:>10}  {:>8}", "-", "-"),
+                }
+            }
+
+            table += "\n";
+        }
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzles;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    fn run(part: Part, parse: u64, solve: u64) -> Run {
+        Run {
+            day: 1,
+            directory: "day01",
+            part,
+            benchmark: Benchmark {
+                parse: Summary::new(&[ms(parse)]),
+                solve: Summary::new(&[ms(solve)]),
+            },
+        }
+    }
+
+    #[test]
+    fn test_summary() {
+        assert_eq!(Summary::new(&[]), Default::default());
+        assert_eq!(
+            Summary::new(&[ms(3), ms(1), ms(2)]),
+            Summary {
+                min: ms(1),
+                median: ms(2),
+                max: ms(3)
+            }
+        );
+        assert_eq!(Summary::new(&[ms(4), ms(1), ms(2), ms(9)]).median, ms(3));
+    }
+
+    #[test]
+    fn test_change() {
+        assert_eq!(change(ms(10), ms(15)), 50.0);
+        assert_eq!(change(ms(10), ms(5)), -50.0);
+        assert_eq!(change(ms(0), ms(5)), 0.0);
+    }
+
+    #[test]
+    fn test_measure() {
+        let puzzle = puzzles::find(1).unwrap();
+        let input = fs::read_to_string(puzzle.input_path("input-test")).unwrap();
+        let samples = (puzzle.bench)(&input, Part::A, 3).unwrap();
+        assert_eq!(samples.parse.len(), 3);
+        assert_eq!(samples.solve.len(), 3);
+        assert!((puzzle.bench)("x\n", Part::A, 3).is_err());
+    }
+
+    #[test]
+    fn test_regressions() {
+        let mut baseline: Baseline = Default::default();
+        baseline.insert("day01", Part::A, run(Part::A, 10, 10).benchmark);
+
+        let runs = [run(Part::A, 10, 12), run(Part::B, 100, 100)];
+        assert_eq!(regressions(&runs, &baseline, 25.0).count(), 0);
+
+        let regressed: Vec<_> = regressions(&runs, &baseline, 10.0)
+            .map(|(run, phase)| (run.part, phase))
+            .collect();
+        assert_eq!(regressed, vec![(Part::A, Phase::Solve)]);
+
+        let report = report(&runs, Some(&baseline), 10.0);
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert!(lines[1].ends_with("+0.0%"));
+        assert!(lines[2].ends_with("+20.0%  regressed"));
+        assert!(lines[3].ends_with("-         -"));
+    }
+}
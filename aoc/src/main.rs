@@ -0,0 +1,361 @@
+mod answers;
+mod bench;
+mod puzzles;
+mod tools;
+mod verify;
+
+use crate::answers::Registry;
+use crate::bench::{Baseline, Benchmark, Run};
+use crate::puzzles::{Detailed, Part, Puzzle, PUZZLES};
+use crate::tools::Tool;
+use anyhow::{bail, Context};
+use clap::{Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+use serde_json::Value;
+use std::fs;
+use std::io::{self, Read};
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Run the Advent of Code 2021 puzzle solvers.
+#[derive(Debug, Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Just the answer.
+    Text,
+    /// An object with the day, part, answer, elapsed seconds, input path and any extras.
+    Json,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve one part of a day's puzzle.
+    Run {
+        /// The day of the puzzle.
+        day: u8,
+
+        /// The part of the puzzle.
+        #[arg(value_enum)]
+        part: Part,
+
+        /// The puzzle input, or `-` to read it from stdin. Defaults to the day's own input file.
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+
+        /// How to print the answer.
+        #[arg(short, long, value_enum, default_value = "text")]
+        format: Format,
+    },
+
+    /// Solve every puzzle for every input with a known answer, and report any that don't match.
+    Verify {
+        /// Only verify this day.
+        day: Option<u8>,
+
+        /// The file of known answers. Defaults to the one in the workspace.
+        #[arg(short, long)]
+        answers: Option<PathBuf>,
+    },
+
+    /// Time the parse and solve phases of the puzzles separately, using each day's own input.
+    Bench {
+        /// Only benchmark this day.
+        day: Option<u8>,
+
+        /// Only benchmark this part.
+        #[arg(short, long, value_enum)]
+        part: Option<Part>,
+
+        /// How many times to run each phase.
+        #[arg(short = 'n', long, default_value = "10")]
+        iterations: NonZeroUsize,
+
+        /// Save the results to a baseline file, replacing any that were saved for the same parts.
+        #[arg(long)]
+        save: Option<PathBuf>,
+
+        /// Compare the results against a saved baseline file.
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+
+        /// How many percent slower a median can be than the baseline before it's a regression.
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+
+    /// Generate random input for a day's puzzle.
+    Gen {
+        /// The day of the puzzle.
+        day: u8,
+
+        /// The seed for the random numbers. Defaults to one based on the time, which is printed.
+        #[arg(short, long)]
+        seed: Option<u64>,
+
+        /// How big the input should be, in whatever the puzzle is made of, like boards or lines.
+        /// Defaults to about the size of the real input.
+        #[arg(short = 'n', long)]
+        size: Option<usize>,
+
+        /// Where to write the input. Defaults to stdout.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Where to write what the input was built from, as JSON, for puzzles that know it.
+        #[arg(short, long)]
+        truth: Option<PathBuf>,
+    },
+
+    /// Explore a day's puzzle beyond its answers.
+    Tool {
+        #[command(subcommand)]
+        tool: Tool,
+    },
+}
+
+fn read_input(path: &Path) -> anyhow::Result<String> {
+    if path.as_os_str() == "-" {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .context("unable to read input from stdin")?;
+        return Ok(input);
+    }
+
+    fs::read_to_string(path)
+        .with_context(|| format!("unable to read input file {}", path.display()))
+}
+
+/// The output of a solver in the JSON format.
+#[derive(Debug, Serialize)]
+struct Output<'a> {
+    day: u8,
+    part: Part,
+    answer: String,
+    /// In seconds.
+    elapsed: f64,
+    input: &'a Path,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    extras: Option<Value>,
+}
+
+impl<'a> Output<'a> {
+    fn new(puzzle: &Puzzle, part: Part, input: &'a Path, detailed: Detailed) -> Self {
+        Self {
+            day: puzzle.day,
+            part,
+            answer: detailed.answer,
+            elapsed: detailed.elapsed.as_secs_f64(),
+            input,
+            extras: detailed.extras,
+        }
+    }
+}
+
+/// Either the puzzle for the given day, or all of them.
+fn select_puzzles(day: Option<u8>) -> anyhow::Result<Vec<&'static Puzzle>> {
+    match day {
+        Some(day) => {
+            Ok(vec![puzzles::find(day).with_context(|| {
+                format!("there is no solver for day {}", day)
+            })?])
+        }
+        None => Ok(PUZZLES.iter().collect()),
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+        } => {
+            let puzzle = puzzles::find(day)
+                .with_context(|| format!("there is no solver for day {}", day))?;
+            let path = input.unwrap_or_else(|| puzzle.default_input());
+            let input = read_input(&path)?;
+            let context = || format!("unable to parse input file {}", path.display());
+
+            match format {
+                Format::Text => {
+                    let answer = (puzzle.solve)(&input, part).with_context(context)?;
+                    println!("{}", answer);
+                }
+                Format::Json => {
+                    let detailed = (puzzle.detail)(&input, part).with_context(context)?;
+                    let output = Output::new(puzzle, part, &path, detailed);
+                    println!("{}", serde_json::to_string(&output)?);
+                }
+            }
+        }
+        Command::Verify { day, answers } => {
+            let puzzles = select_puzzles(day)?;
+            let path = answers.unwrap_or_else(Registry::default_path);
+            let registry = Registry::load(&path)?;
+
+            let checks = verify::verify(&registry, puzzles);
+            print!("{}", verify::report(&checks));
+
+            let failures = checks.iter().filter(|check| !check.passed()).count();
+            if failures > 0 {
+                bail!("{} of {} answers did not verify", failures, checks.len());
+            }
+        }
+        Command::Bench {
+            day,
+            part,
+            iterations,
+            save,
+            baseline,
+            threshold,
+        } => {
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![Part::A, Part::B],
+            };
+            let baseline = baseline.as_deref().map(Baseline::load).transpose()?;
+
+            let mut runs: Vec<Run> = Default::default();
+
+            for puzzle in select_puzzles(day)? {
+                let path = puzzle.default_input();
+                let input = read_input(&path)?;
+
+                for &part in parts.iter() {
+                    let samples =
+                        (puzzle.bench)(&input, part, iterations.get()).with_context(|| {
+                            format!("unable to parse input file {}", path.display())
+                        })?;
+                    runs.push(Run {
+                        day: puzzle.day,
+                        directory: puzzle.directory,
+                        part,
+                        benchmark: Benchmark::from(&samples),
+                    });
+                }
+            }
+
+            print!("{}", bench::report(&runs, baseline.as_ref(), threshold));
+
+            if let Some(path) = save {
+                let mut saved = if path.exists() {
+                    Baseline::load(&path)?
+                } else {
+                    Default::default()
+                };
+                for run in runs.iter() {
+                    saved.insert(run.directory, run.part, run.benchmark);
+                }
+                saved.save(&path)?;
+            }
+
+            if let Some(baseline) = baseline {
+                let regressions = bench::regressions(&runs, &baseline, threshold).count();
+                if regressions > 0 {
+                    bail!(
+                        "{} phases regressed by more than {}% against the baseline",
+                        regressions,
+                        threshold
+                    );
+                }
+            }
+        }
+        Command::Gen {
+            day,
+            seed,
+            size,
+            output,
+            truth,
+        } => {
+            let puzzle = puzzles::find(day)
+                .with_context(|| format!("there is no solver for day {}", day))?;
+            let generator = puzzle
+                .generator
+                .as_ref()
+                .with_context(|| format!("there is no generator for day {}", day))?;
+
+            let seed = match seed {
+                Some(seed) => seed,
+                None => {
+                    let now = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH)?;
+                    let seed = now.as_nanos() as u64;
+                    eprintln!("seed: {}", seed);
+                    seed
+                }
+            };
+
+            let generated = (generator.generate)(seed, size.unwrap_or(generator.default_size));
+
+            match output {
+                Some(path) => fs::write(&path, &generated.input)
+                    .with_context(|| format!("unable to write input file {}", path.display()))?,
+                None => print!("{}", generated.input),
+            }
+
+            if let Some(path) = truth {
+                let truth = generated
+                    .truth
+                    .with_context(|| format!("the generator for day {} has no truth", day))?;
+                fs::write(&path, serde_json::to_string_pretty(&truth)? + "\n")
+                    .with_context(|| format!("unable to write truth file {}", path.display()))?;
+            }
+        }
+        Command::Tool { tool } => tools::run(tool, io::stdin().lock(), &mut io::stdout().lock())?,
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_input() {
+        let path = puzzles::find(1).unwrap().default_input();
+        assert_eq!(
+            read_input(&path).unwrap(),
+            fs::read_to_string(&path).unwrap()
+        );
+
+        let error = read_input(Path::new("does-not-exist")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unable to read input file does-not-exist"
+        );
+    }
+
+    #[test]
+    fn test_output() {
+        let puzzle = puzzles::find(12).unwrap();
+        let path = puzzle.input_path("input-test-small");
+        let input = fs::read_to_string(&path).unwrap();
+        let detailed = (puzzle.detail)(&input, Part::B).unwrap();
+        let output = serde_json::to_value(Output::new(puzzle, Part::B, &path, detailed)).unwrap();
+
+        assert_eq!(output["day"], 12);
+        assert_eq!(output["part"], "b");
+        assert_eq!(output["answer"], "36");
+        assert!(output["elapsed"].as_f64().unwrap() >= 0.0);
+        assert_eq!(output["input"], path.to_str().unwrap());
+        assert_eq!(output["extras"]["paths"]["revisiting_one_small_cave"], 36);
+
+        let puzzle = puzzles::find(1).unwrap();
+        let path = puzzle.input_path("input-test");
+        let input = fs::read_to_string(&path).unwrap();
+        let detailed = (puzzle.detail)(&input, Part::A).unwrap();
+        let output = serde_json::to_value(Output::new(puzzle, Part::A, &path, detailed)).unwrap();
+        assert!(output.get("extras").is_none());
+    }
+}
@@ -0,0 +1,181 @@
+use crate::bench::{self, Samples};
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::Value;
+use solution::{Generated, Generator, ParseError, Solution};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Part {
+    A,
+    B,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::A => write!(f, "a"),
+            Part::B => write!(f, "b"),
+        }
+    }
+}
+
+/// Parses the input and solves one part of it, giving the answer as it should be printed.
+pub type Solver = fn(&str, Part) -> Result<String, ParseError>;
+
+/// The answer to one part of a puzzle, with how long it took to parse and solve, and the puzzle's
+/// extras.
+#[derive(Debug, Clone)]
+pub struct Detailed {
+    pub answer: String,
+    pub elapsed: Duration,
+    pub extras: Option<Value>,
+}
+
+/// Like a [`Solver`], but also gives the details that are reported in machine-readable output.
+pub type Detailer = fn(&str, Part) -> Result<Detailed, ParseError>;
+
+/// Times parsing the input and solving one part of it separately, over a number of iterations.
+pub type Bencher = fn(&str, Part, usize) -> Result<Samples, ParseError>;
+
+/// Generates random input from a seed and a size.
+pub type Generate = fn(u64, usize) -> Generated;
+
+#[derive(Debug)]
+pub struct InputGenerator {
+    pub generate: Generate,
+    pub default_size: usize,
+}
+
+#[derive(Debug)]
+pub struct Puzzle {
+    pub day: u8,
+    pub directory: &'static str,
+    pub solve: Solver,
+    pub detail: Detailer,
+    pub bench: Bencher,
+    pub generator: Option<InputGenerator>,
+}
+
+impl Puzzle {
+    const fn new<S: Solution>(day: u8, directory: &'static str) -> Self {
+        Self {
+            day,
+            directory,
+            solve: solve::<S>,
+            detail: detail::<S>,
+            bench: bench::measure::<S>,
+            generator: None,
+        }
+    }
+
+    const fn with_generator<G: Generator>(self) -> Self {
+        Self {
+            generator: Some(InputGenerator {
+                generate: solution::generate::<G>,
+                default_size: G::DEFAULT_SIZE,
+            }),
+            ..self
+        }
+    }
+
+    /// The path of one of the input files kept in the day's directory.
+    pub fn input_path(&self, name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(self.directory)
+            .join(name)
+    }
+
+    pub fn default_input(&self) -> PathBuf {
+        self.input_path("input")
+    }
+}
+
+fn solve<S: Solution>(input: &str, part: Part) -> Result<String, ParseError> {
+    let input = S::parse(input)?;
+    Ok(match part {
+        Part::A => S::solve_part_a(&input).to_string(),
+        Part::B => S::solve_part_b(&input).to_string(),
+    })
+}
+
+// The extras aren't included in the elapsed time, since they usually repeat some of the work.
+fn detail<S: Solution>(input: &str, part: Part) -> Result<Detailed, ParseError> {
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let answer = match part {
+        Part::A => S::solve_part_a(&input).to_string(),
+        Part::B => S::solve_part_b(&input).to_string(),
+    };
+    let elapsed = start.elapsed();
+
+    Ok(Detailed {
+        answer,
+        elapsed,
+        extras: S::extras(&input),
+    })
+}
+
+pub const PUZZLES: [Puzzle; 19] = [
+    Puzzle::new::<day01::Day01>(1, "day01").with_generator::<day01::Day01>(),
+    Puzzle::new::<day02::Day02>(2, "day02").with_generator::<day02::Day02>(),
+    Puzzle::new::<day03::Day03>(3, "day03").with_generator::<day03::Day03>(),
+    Puzzle::new::<day04::Day04>(4, "day04").with_generator::<day04::Day04>(),
+    Puzzle::new::<day05::Day05>(5, "day05").with_generator::<day05::Day05>(),
+    Puzzle::new::<day06::Day06>(6, "day06").with_generator::<day06::Day06>(),
+    Puzzle::new::<day07::Day07>(7, "day07").with_generator::<day07::Day07>(),
+    Puzzle::new::<day08::Day08>(8, "day08").with_generator::<day08::Day08>(),
+    Puzzle::new::<day09::Day09>(9, "day09").with_generator::<day09::Day09>(),
+    Puzzle::new::<day10::Day10>(10, "day10").with_generator::<day10::Day10>(),
+    Puzzle::new::<day11::Day11>(11, "day11").with_generator::<day11::Day11>(),
+    Puzzle::new::<day12::Day12>(12, "day12").with_generator::<day12::Day12>(),
+    Puzzle::new::<day13::Day13>(13, "day13").with_generator::<day13::Day13>(),
+    Puzzle::new::<day14::Day14>(14, "day14").with_generator::<day14::Day14>(),
+    Puzzle::new::<day15::Day15>(15, "day15").with_generator::<day15::Day15>(),
+    Puzzle::new::<day16::Day16>(16, "day16").with_generator::<day16::Day16>(),
+    Puzzle::new::<day17::Day17>(17, "day17").with_generator::<day17::Day17>(),
+    Puzzle::new::<day18::Day18>(18, "day18").with_generator::<day18::Day18>(),
+    Puzzle::new::<day19::Day19>(19, "day19").with_generator::<day19::Day19>(),
+];
+
+pub fn find(day: u8) -> Option<&'static Puzzle> {
+    PUZZLES.iter().find(|puzzle| puzzle.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        for day in 1..=19 {
+            let puzzle = find(day).unwrap();
+            assert_eq!(puzzle.directory, format!("day{:02}", day));
+            assert!(puzzle.default_input().exists());
+        }
+        assert!(find(0).is_none());
+        assert!(find(20).is_none());
+    }
+
+    #[test]
+    fn test_generators() {
+        let days: Vec<u8> = PUZZLES
+            .iter()
+            .filter(|puzzle| puzzle.generator.is_some())
+            .map(|puzzle| puzzle.day)
+            .collect();
+        assert_eq!(days, (1..=19).collect::<Vec<u8>>());
+
+        for day in days {
+            let puzzle = find(day).unwrap();
+            let generator = puzzle.generator.as_ref().unwrap();
+            let generated = (generator.generate)(1, generator.default_size.min(5));
+            assert!((puzzle.solve)(&generated.input, Part::A).is_ok());
+            assert!((puzzle.solve)(&generated.input, Part::B).is_ok());
+        }
+    }
+}
@@ -0,0 +1,466 @@
+use anyhow::Context;
+use clap::Subcommand;
+use day01::{Aggregation, Detector, SlidingWindows, Trends};
+use day02::{Model, Surfacing};
+use day03::Day03;
+use day04::{Day04, Rule, Simulation};
+use day05::{Day05, Rasterization};
+use day06::{Day06, Lifecycle};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use solution::Solution;
+use std::io::{BufRead, BufWriter, Write};
+use std::num::NonZeroUsize;
+
+/// The tools for exploring a day's puzzle beyond its answers, which all read their input from
+/// stdin.
+#[derive(Debug, Subcommand)]
+pub enum Tool {
+    /// Day 1: show how the aggregates of sliding windows of depths trend. Depths are read as they
+    /// arrive, so there's no limit to how many there can be.
+    Analyze {
+        /// How many depths are in each window.
+        #[arg(default_value = "3")]
+        size: NonZeroUsize,
+
+        /// How the depths in a window are combined: sum, mean, min, max or median.
+        #[arg(default_value_t = Aggregation::Sum)]
+        aggregation: Aggregation,
+    },
+
+    /// Day 1: print each anomalous depth as soon as it's found.
+    Anomalies {
+        /// How many of the preceding depths an anomaly is judged against.
+        #[arg(short, long, default_value_t = Detector::default().window)]
+        window: usize,
+
+        /// How many standard deviations from the window's mean makes a depth anomalous.
+        #[arg(short = 'k', long)]
+        deviations: Option<f64>,
+
+        /// How far a depth can jump from the one before it without being anomalous.
+        #[arg(short, long)]
+        jump: Option<u32>,
+    },
+
+    /// Day 2: run a script under each of the given models.
+    Eval {
+        /// What happens when the submarine goes above the surface: clamp, error or allow.
+        #[arg(short, long, default_value_t = Surfacing::default())]
+        surfacing: Surfacing,
+
+        /// The models to run the script under: direct, aimed or oriented. Defaults to all of them.
+        models: Vec<Model>,
+    },
+
+    /// Day 3: show the counts in each column and how each rating was chosen.
+    Report,
+
+    /// Day 4: play a session, showing what happened each turn and how the boards ranked.
+    Replay {
+        /// What a board needs to mark to win: lines, diagonals, full-card or four-corners.
+        #[arg(default_value_t = Rule::default())]
+        rule: Rule,
+    },
+
+    /// Day 4: estimate how each board in a session fares over random draw orders.
+    Simulate {
+        /// How many draw orders to play.
+        #[arg(short, long, default_value_t = Simulation::default().trials)]
+        trials: usize,
+
+        /// The seed for the draw orders, and for any generated boards.
+        #[arg(short, long, default_value_t = Simulation::default().seed)]
+        seed: u64,
+
+        /// What a board needs to mark to win: lines, diagonals, full-card or four-corners.
+        #[arg(short, long, default_value_t = Rule::default())]
+        rule: Rule,
+
+        /// Generate this many random boards instead of reading a session, and print them.
+        #[arg(short, long)]
+        generate: Option<usize>,
+
+        /// How wide the generated boards are.
+        #[arg(short = 'z', long, default_value = "5")]
+        size: NonZeroUsize,
+    },
+
+    /// Day 5: count the overlapping points with each of the given rasterizations.
+    Overlaps {
+        /// The ways to turn lines into points: lattice, bresenham or supercover. Defaults to all
+        /// of them.
+        rasterizations: Vec<Rasterization>,
+    },
+
+    /// Day 5: write the number of lines covering each cell of points to stdout.
+    Heatmap {
+        /// The format to write: pgm, ppm or csv.
+        #[arg(short, long, default_value_t = day05::Format::default())]
+        format: day05::Format,
+
+        /// How many points wide and high each cell is.
+        #[arg(short, long, default_value = "1")]
+        bin: NonZeroUsize,
+
+        /// The way to turn lines into points: lattice, bresenham or supercover.
+        #[arg(short, long, default_value_t = Rasterization::default())]
+        rasterization: Rasterization,
+    },
+
+    /// Day 6: print the exact number of fish after a number of days.
+    Lanternfish {
+        /// How many days to count the fish for.
+        #[arg(short, long, default_value = "256")]
+        days: u64,
+
+        /// The timer of a fish after it spawns.
+        #[arg(short, long, default_value_t = Lifecycle::default().reset)]
+        reset: u8,
+
+        /// The timer of a newly spawned fish.
+        #[arg(short, long, default_value_t = Lifecycle::default().newborn)]
+        newborn: u8,
+    },
+}
+
+fn read_all(mut input: impl BufRead) -> anyhow::Result<String> {
+    let mut s = String::new();
+    input
+        .read_to_string(&mut s)
+        .context("unable to read input")?;
+    Ok(s)
+}
+
+/// Reads depths one line at a time, stopping at the first that can't be read, which is kept in
+/// `failure`.
+fn read_depths<'a>(
+    input: impl BufRead + 'a,
+    failure: &'a mut Option<anyhow::Error>,
+) -> impl Iterator<Item = u32> + 'a {
+    input.lines().enumerate().map_while(move |(i, line)| {
+        let depth = match line {
+            Ok(line) => day01::parse_depth(i + 1, &line).map_err(anyhow::Error::from),
+            Err(error) => Err(anyhow::Error::from(error).context("unable to read input")),
+        };
+        depth.map_err(|error| *failure = Some(error)).ok()
+    })
+}
+
+pub fn run(tool: Tool, input: impl BufRead, output: &mut impl Write) -> anyhow::Result<()> {
+    match tool {
+        Tool::Analyze { size, aggregation } => {
+            let mut failure = None;
+            let trends: Trends = read_depths(input, &mut failure)
+                .sliding_windows(size.get(), aggregation)
+                .collect();
+
+            if let Some(error) = failure {
+                return Err(error);
+            }
+
+            writeln!(output, "windows: {}", trends.count)?;
+            writeln!(output, "increases: {}", trends.increases)?;
+            writeln!(output, "decreases: {}", trends.decreases)?;
+            writeln!(output, "plateaus: {}", trends.plateaus)?;
+
+            match trends.longest_run {
+                Some(run) => writeln!(
+                    output,
+                    "longest run: {} {} windows, starting at window {}",
+                    run.length,
+                    run.direction,
+                    run.start + 1
+                )?,
+                None => writeln!(output, "longest run: none")?,
+            }
+        }
+        Tool::Anomalies {
+            window,
+            deviations,
+            jump,
+        } => {
+            anyhow::ensure!(window > 0, "the window must hold at least one depth");
+
+            let mut detector = Detector {
+                window,
+                jump,
+                ..Default::default()
+            };
+            if deviations.is_some() {
+                detector.deviations = deviations;
+            }
+
+            let mut failure = None;
+            for anomaly in detector.detect(read_depths(input, &mut failure)) {
+                writeln!(output, "{}", anomaly)?;
+                output.flush()?;
+            }
+
+            if let Some(error) = failure {
+                return Err(error);
+            }
+        }
+        Tool::Eval {
+            surfacing,
+            mut models,
+        } => {
+            if models.is_empty() {
+                models = Model::ALL.to_vec();
+            }
+
+            let statements = day02::parse_script(&read_all(input)?)?;
+
+            for model in models {
+                match day02::evaluate(&statements, model, surfacing) {
+                    Ok(coordinates) => writeln!(
+                        output,
+                        "{}: distance {}, depth {}, drift {}, product {}",
+                        model,
+                        coordinates.distance,
+                        coordinates.depth,
+                        coordinates.drift,
+                        coordinates.product()
+                    )?,
+                    Err(error) => writeln!(output, "{}: {}", model, error)?,
+                }
+            }
+        }
+        Tool::Report => {
+            let numbers = Day03::parse(&read_all(input)?)?;
+            write!(output, "{}", day03::report(&numbers))?;
+        }
+        Tool::Replay { rule } => {
+            let session = Day04::parse(&read_all(input)?)?;
+            let replay = session.play(rule);
+
+            for turn in replay.turns.iter() {
+                writeln!(output, "{}", turn)?;
+            }
+
+            writeln!(output, "ranking:")?;
+            for (place, win) in replay.wins().enumerate() {
+                writeln!(
+                    output,
+                    "  {}. board {} on turn {}, scoring {}",
+                    place + 1,
+                    win.board,
+                    win.turn + 1,
+                    win.score
+                )?;
+            }
+
+            if !replay.losers.is_empty() {
+                let losers: Vec<String> = replay.losers.iter().map(|i| i.to_string()).collect();
+                writeln!(output, "never won: boards {}", losers.join(", "))?;
+            }
+        }
+        Tool::Simulate {
+            trials,
+            seed,
+            rule,
+            generate,
+            size,
+        } => {
+            anyhow::ensure!(trials > 0, "there must be at least one trial");
+
+            let simulation = Simulation { trials, seed, rule };
+
+            // Random boards come from the same seed as the draw orders.
+            let session = match generate {
+                Some(boards) => {
+                    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+                    let size = size.get();
+                    let numbers = (size * size).max(100);
+                    let session = day04::random_session(&mut rng, boards, size, numbers);
+                    writeln!(output, "{}", session)?;
+                    session
+                }
+                None => Day04::parse(&read_all(input)?)?,
+            };
+
+            writeln!(output, "{}", simulation.run(&session))?;
+        }
+        Tool::Overlaps { rasterizations } => {
+            let rasterizations = if rasterizations.is_empty() {
+                Rasterization::ALL.to_vec()
+            } else {
+                rasterizations
+            };
+
+            let lines = Day05::parse(&read_all(input)?)?;
+
+            for rasterization in rasterizations {
+                writeln!(
+                    output,
+                    "{}: {}",
+                    rasterization,
+                    day05::count_overlapping_points(&lines, rasterization)
+                )?;
+            }
+        }
+        Tool::Heatmap {
+            format,
+            bin,
+            rasterization,
+        } => {
+            let lines = Day05::parse(&read_all(input)?)?;
+            let bin = bin.get().try_into().context("the bin size is too big")?;
+            let heatmap = day05::Heatmap::new(&day05::get_hits(&lines, rasterization), bin)
+                .context("try a bigger --bin")?;
+
+            let mut output = BufWriter::new(output);
+            heatmap.write(format, &mut output)?;
+            output.flush()?;
+        }
+        Tool::Lanternfish {
+            days,
+            reset,
+            newborn,
+        } => {
+            let lanternfish = Day06::parse(&read_all(input)?)?;
+            let lifecycle = Lifecycle { reset, newborn };
+            writeln!(
+                output,
+                "{}",
+                day06::count_lanternfish(&lanternfish, days, lifecycle)
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use std::fs;
+
+    #[derive(Debug, Parser)]
+    struct Cli {
+        #[command(subcommand)]
+        tool: Tool,
+    }
+
+    fn run_tool(args: &[&str], input: &str) -> String {
+        let cli = Cli::try_parse_from(["tool"].iter().chain(args)).unwrap();
+        let mut output = Vec::new();
+        run(cli.tool, input.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_analyze() {
+        let input = fs::read_to_string("../day01/input-test").unwrap();
+        let output = run_tool(&["analyze"], &input);
+        assert!(output.starts_with("windows: 8\nincreases: 5\n"));
+    }
+
+    #[test]
+    fn test_anomalies() {
+        let input = fs::read_to_string("../day01/input-test").unwrap();
+        let output = run_tool(&["anomalies", "-w", "3", "-k", "1"], &input);
+        assert_eq!(output.lines().count(), 4);
+        assert!(output.starts_with("line 4: depth 210 is 1.9 standard deviations"));
+    }
+
+    #[test]
+    fn test_eval() {
+        let input = fs::read_to_string("../day02/input-test").unwrap();
+        assert_eq!(
+            run_tool(&["eval", "-s", "allow", "aimed"], &input),
+            "aimed: distance 15, depth 60, drift 0, product 900\n"
+        );
+
+        // Each model reports its own error, even for a script that the puzzle would reject.
+        let input = "down 9223372036854775807\nforward 2\n";
+        assert_eq!(
+            run_tool(&["eval", "direct", "aimed"], input),
+            "direct: distance 2, depth 9223372036854775807, drift 0, product 18446744073709551614\n\
+             aimed: command 1 (line 2): the submarine went out of range\n"
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        let input = fs::read_to_string("../day03/input-test").unwrap();
+        let output = run_tool(&["report"], &input);
+        assert!(output
+            .ends_with("  bit 2: 1 ones, 1 zeros, kept 0s, eliminated 1:\n    line 6: 01111\n"));
+    }
+
+    #[test]
+    fn test_replay() {
+        let input = fs::read_to_string("../day04/input-test").unwrap();
+        let output = run_tool(&["replay"], &input);
+        assert!(output.ends_with(
+            "ranking:\n  \
+             1. board 2 on turn 12, scoring 4512\n  \
+             2. board 0 on turn 14, scoring 2192\n  \
+             3. board 1 on turn 15, scoring 1924\n"
+        ));
+
+        let error = Cli::try_parse_from(["tool", "replay", "sideways"]).unwrap_err();
+        assert!(error.to_string().contains("unknown rule: sideways"));
+    }
+
+    #[test]
+    fn test_simulate() {
+        let input = fs::read_to_string("../day04/input-test").unwrap();
+        let output = run_tool(&["simulate", "-t", "20"], &input);
+        assert!(output.starts_with("board   first    last   never   draws\n"));
+        assert!(output.ends_with("(20 trials)\n"));
+
+        // Generated boards are printed before the estimate, and don't need any input.
+        let output = run_tool(&["simulate", "-t", "20", "-g", "2", "-z", "3"], "");
+        let (session, estimate) = output.split_once("board ").unwrap();
+        let session = format!(
+            "{}
+",
+            session.trim_end()
+        );
+        assert!(Day04::parse(&session).is_ok());
+        assert_eq!(
+            session
+                .split(
+                    "
+
+"
+                )
+                .count(),
+            3
+        );
+        assert_eq!(estimate.lines().count(), 4);
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let input = fs::read_to_string("../day05/input-test").unwrap();
+        assert_eq!(run_tool(&["overlaps", "lattice"], &input), "lattice: 12\n");
+    }
+
+    #[test]
+    fn test_heatmap() {
+        let input = "0,0 -> 2,2\n2,0 -> 0,2\n";
+        assert_eq!(
+            run_tool(&["heatmap", "-f", "csv"], input),
+            "1,0,1\n0,2,0\n1,0,1\n"
+        );
+
+        let input = "0,0 -> 0,0\n999999999,999999999 -> 999999999,999999999\n";
+        let cli = Cli::try_parse_from(["tool", "heatmap"]).unwrap();
+        let error = run(cli.tool, input.as_bytes(), &mut Vec::new()).unwrap_err();
+        assert_eq!(error.to_string(), "try a bigger --bin");
+        assert_eq!(
+            run_tool(&["heatmap", "-f", "csv", "-b", "1000000000"], input),
+            "2\n"
+        );
+    }
+
+    #[test]
+    fn test_lanternfish() {
+        let input = fs::read_to_string("../day06/input-test").unwrap();
+        assert_eq!(run_tool(&["lanternfish", "-d", "80"], &input), "5934\n");
+    }
+}
@@ -0,0 +1,204 @@
+use crate::answers::Registry;
+use crate::puzzles::{Part, Puzzle};
+use std::fmt;
+use std::fs;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// The solver couldn't be run at all, because the input couldn't be read or parsed.
+    Error(String),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Pass => write!(f, "pass"),
+            Outcome::Fail { .. } => write!(f, "FAIL"),
+            Outcome::Error(_) => write!(f, "ERROR"),
+        }
+    }
+}
+
+/// The result of solving one part of a puzzle for one of its inputs.
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        self.outcome == Outcome::Pass
+    }
+}
+
+fn check(puzzle: &Puzzle, input: &str, part: Part, expected: &str) -> Check {
+    let mut check = Check {
+        day: puzzle.day,
+        part,
+        input: input.to_string(),
+        outcome: Outcome::Pass,
+        elapsed: Default::default(),
+    };
+
+    let path = puzzle.input_path(input);
+    let s = match fs::read_to_string(&path) {
+        Ok(s) => s,
+        Err(error) => {
+            check.outcome = Outcome::Error(format!("{}: {}", path.display(), error));
+            return check;
+        }
+    };
+
+    let start = Instant::now();
+    let result = (puzzle.solve)(&s, part);
+    check.elapsed = start.elapsed();
+
+    check.outcome = match result {
+        Ok(actual) if actual == expected => Outcome::Pass,
+        Ok(actual) => Outcome::Fail {
+            expected: expected.to_string(),
+            actual,
+        },
+        Err(error) => Outcome::Error(error.to_string()),
+    };
+
+    check
+}
+
+/// Solves every part of the given puzzles, for every input that has a known answer.
+pub fn verify<'a>(
+    registry: &Registry,
+    puzzles: impl IntoIterator<Item = &'a Puzzle>,
+) -> Vec<Check> {
+    let mut checks: Vec<Check> = Default::default();
+
+    for puzzle in puzzles {
+        for (input, answers) in registry.inputs(puzzle.directory) {
+            for part in [Part::A, Part::B] {
+                if let Some(expected) = answers.get(part) {
+                    checks.push(check(puzzle, input, part, expected));
+                }
+            }
+        }
+    }
+
+    checks
+}
+
+/// Formats the checks as a table, followed by the details of anything that didn't pass.
+pub fn report(checks: &[Check]) -> String {
+    let width = checks
+        .iter()
+        .map(|check| check.input.len())
+        .chain(["input".len()])
+        .max()
+        .unwrap_or_default();
+
+    let mut table = format!(
+        "{:>3}  {:4}  {:width$}  {:6}  {:>10}\n",
+        "day",
+        "part",
+        "input",
+        "result",
+        "time",
+        width = width
+    );
+
+    for check in checks {
+        table += &format!(
+            "{:>3}  {:4}  {:width$}  {:6}  {:>10}\n",
+            check.day,
+            check.part.to_string(),
+            check.input,
+            check.outcome.to_string(),
+            format!("{:.1?}", check.elapsed),
+            width = width
+        );
+    }
+
+    for check in checks.iter().filter(|check| !check.passed()) {
+        table += &format!(
+            "\nday {} part {} ({}):\n",
+            check.day, check.part, check.input
+        );
+
+        match &check.outcome {
+            Outcome::Fail { expected, actual } => {
+                table += &format!("expected:\n{}\nactual:\n{}\n", expected, actual);
+            }
+            Outcome::Error(error) => table += &format!("{}\n", error),
+            Outcome::Pass => unreachable!(),
+        }
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::Answers;
+    use crate::puzzles;
+
+    #[test]
+    fn test_verify() {
+        let registry: Registry = [
+            (
+                "day01".to_string(),
+                "input-test".to_string(),
+                Answers {
+                    a: Some("7".to_string()),
+                    b: Some("6".to_string()),
+                },
+            ),
+            (
+                "day01".to_string(),
+                "does-not-exist".to_string(),
+                Answers {
+                    a: Some("0".to_string()),
+                    b: None,
+                },
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let checks = verify(&registry, puzzles::find(1));
+        let outcomes: Vec<_> = checks
+            .iter()
+            .map(|check| (check.input.as_str(), check.part, &check.outcome))
+            .collect();
+
+        assert_eq!(outcomes.len(), 3);
+        assert!(matches!(
+            outcomes[0],
+            ("does-not-exist", Part::A, Outcome::Error(_))
+        ));
+        assert_eq!(outcomes[1], ("input-test", Part::A, &Outcome::Pass));
+        assert_eq!(
+            outcomes[2],
+            (
+                "input-test",
+                Part::B,
+                &Outcome::Fail {
+                    expected: "6".to_string(),
+                    actual: "5".to_string()
+                }
+            )
+        );
+
+        let report = report(&checks);
+        assert!(report.starts_with("day  part  input           result"));
+        assert!(report.contains("\nday 1 part b (input-test):\nexpected:\n6\nactual:\n5\n"));
+    }
+}
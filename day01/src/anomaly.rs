@@ -0,0 +1,248 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+/// What makes a reading suspicious. A reading is only compared to the rolling window once the
+/// window is full, and never if every reading in it was the same, since there's no spread to
+/// measure against.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Detector {
+    /// How many of the previous readings make up the rolling window.
+    pub window: usize,
+    /// How many standard deviations from the window's mean a reading can be.
+    pub deviations: Option<f64>,
+    /// How much a reading can differ from the one right before it.
+    pub jump: Option<u32>,
+}
+
+impl Default for Detector {
+    fn default() -> Self {
+        Self {
+            window: 10,
+            deviations: Some(3.0),
+            jump: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Reason {
+    Deviation { mean: f64, deviations: f64 },
+    Jump { previous: u32 },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Anomaly {
+    /// The line of the input, starting at 1.
+    pub line: usize,
+    pub depth: u32,
+    pub reasons: Vec<Reason>,
+}
+
+impl fmt::Display for Anomaly {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: depth {}", self.line, self.depth)?;
+
+        for (i, reason) in self.reasons.iter().enumerate() {
+            write!(f, "{}", if i == 0 { " is " } else { " and " })?;
+            match reason {
+                Reason::Deviation { mean, deviations } => write!(
+                    f,
+                    "{:.1} standard deviations from the rolling mean of {:.1}",
+                    deviations, mean
+                )?,
+                Reason::Jump { previous } => write!(
+                    f,
+                    "{} away from the previous depth of {}",
+                    self.depth.abs_diff(*previous),
+                    previous
+                )?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// An iterator over the anomalies in a sequence of depths, which only holds on to the depths in
+/// the rolling window.
+#[derive(Debug, Clone)]
+pub struct Anomalies<I> {
+    depths: I,
+    detector: Detector,
+    line: usize,
+    window: VecDeque<u32>,
+    // Kept as integers so that they stay exact however long the stream goes on.
+    sum: u128,
+    sum_of_squares: u128,
+}
+
+impl<I> Anomalies<I> {
+    fn check(&self, depth: u32) -> Vec<Reason> {
+        let mut reasons: Vec<Reason> = Default::default();
+
+        if let Some(limit) = self.detector.deviations {
+            if self.window.len() == self.detector.window {
+                let n = self.window.len() as u128;
+                let mean = self.sum as f64 / n as f64;
+                // Worked out exactly before dividing, since the squares are far bigger than the
+                // spread between them for deep readings.
+                let spread = n * self.sum_of_squares - self.sum * self.sum;
+                let variance = spread as f64 / (n * n) as f64;
+                let deviations = (depth as f64 - mean).abs() / variance.sqrt();
+
+                if variance > 0.0 && deviations > limit {
+                    reasons.push(Reason::Deviation { mean, deviations });
+                }
+            }
+        }
+
+        if let (Some(limit), Some(&previous)) = (self.detector.jump, self.window.back()) {
+            if depth.abs_diff(previous) > limit {
+                reasons.push(Reason::Jump { previous });
+            }
+        }
+
+        reasons
+    }
+
+    fn push(&mut self, depth: u32) {
+        let value = depth as u128;
+        self.window.push_back(depth);
+        self.sum += value;
+        self.sum_of_squares += value * value;
+
+        if self.window.len() > self.detector.window.max(1) {
+            let value = self.window.pop_front().unwrap() as u128;
+            self.sum -= value;
+            self.sum_of_squares -= value * value;
+        }
+    }
+}
+
+impl<I: Iterator<Item = u32>> Iterator for Anomalies<I> {
+    type Item = Anomaly;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(depth) = self.depths.next() {
+            self.line += 1;
+
+            let reasons = self.check(depth);
+            self.push(depth);
+
+            if !reasons.is_empty() {
+                return Some(Anomaly {
+                    line: self.line,
+                    depth,
+                    reasons,
+                });
+            }
+        }
+
+        None
+    }
+}
+
+impl Detector {
+    /// Finds the anomalies in depths that were read one per line.
+    pub fn detect<I: IntoIterator<Item = u32>>(&self, depths: I) -> Anomalies<I::IntoIter> {
+        Anomalies {
+            depths: depths.into_iter(),
+            detector: *self,
+            line: 0,
+            window: VecDeque::with_capacity(self.window + 1),
+            sum: 0,
+            sum_of_squares: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deviations() {
+        let detector = Detector {
+            window: 4,
+            deviations: Some(3.0),
+            jump: None,
+        };
+
+        let depths = [100, 102, 98, 101, 99, 150, 100, 100, 100, 100, 100, 101];
+        let anomalies: Vec<Anomaly> = detector.detect(depths).collect();
+        assert_eq!(anomalies.len(), 1);
+        assert_eq!((anomalies[0].line, anomalies[0].depth), (6, 150));
+        assert!(matches!(
+            anomalies[0].reasons[..],
+            [Reason::Deviation { mean, .. }] if mean == 100.0
+        ));
+
+        // The window is full of the same depth before the last reading, so it's not checked.
+        assert!(anomalies.iter().all(|anomaly| anomaly.line != 12));
+    }
+
+    #[test]
+    fn test_deviations_deep_stream() {
+        let detector = Detector {
+            window: 4,
+            deviations: Some(3.0),
+            jump: None,
+        };
+
+        // Deep enough that the squares can't all be held exactly as floats, for long enough that
+        // any error would build up.
+        let base = 4_000_000_000;
+        let offsets = [0, 2, -2, 1, -1]
+            .iter()
+            .cycle()
+            .take(200_000)
+            .chain([1, -1, 1, -1, 10].iter());
+        let depths = offsets.map(|&offset: &i64| (base + offset) as u32);
+
+        let anomalies: Vec<Anomaly> = detector.detect(depths).collect();
+        assert_eq!(anomalies.len(), 1);
+        assert_eq!(anomalies[0].line, 200_005);
+        assert_eq!(
+            anomalies[0].reasons,
+            [Reason::Deviation {
+                mean: base as f64,
+                deviations: 10.0
+            }]
+        );
+    }
+
+    #[test]
+    fn test_jump() {
+        let detector = Detector {
+            window: 2,
+            deviations: None,
+            jump: Some(10),
+        };
+
+        let lines: Vec<usize> = detector
+            .detect([200, 210, 221, 215, 190])
+            .map(|anomaly| anomaly.line)
+            .collect();
+        assert_eq!(lines, vec![3, 5]);
+    }
+
+    #[test]
+    fn test_display() {
+        let anomaly = Anomaly {
+            line: 7,
+            depth: 150,
+            reasons: vec![
+                Reason::Deviation {
+                    mean: 100.0,
+                    deviations: 35.35,
+                },
+                Reason::Jump { previous: 99 },
+            ],
+        };
+        assert_eq!(
+            anomaly.to_string(),
+            "line 7: depth 150 is 35.4 standard deviations from the rolling mean of 100.0 and 51 \
+             away from the previous depth of 99"
+        );
+    }
+}
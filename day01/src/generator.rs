@@ -0,0 +1,30 @@
+use crate::Day01;
+use rand::Rng;
+use serde_json::json;
+use solution::{Generated, Generator};
+
+/// Sonar depths, where the size is the number of readings. Like the real input, the depths mostly
+/// go deeper, a little at a time. The truth is how many readings are deeper than the one before.
+impl Generator for Day01 {
+    const DEFAULT_SIZE: usize = 2000;
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+        let mut depth: u32 = rng.gen_range(100..200);
+        let mut depths = vec![depth.to_string()];
+        let mut increases = 0;
+
+        for _ in 1..size.max(1) {
+            let change = rng.gen_range(-10..=20);
+            depth = depth.saturating_add_signed(change);
+            if change > 0 {
+                increases += 1;
+            }
+            depths.push(depth.to_string());
+        }
+
+        Generated {
+            input: depths.join("\n") + "\n",
+            truth: Some(json!({ "increases": increases })),
+        }
+    }
+}
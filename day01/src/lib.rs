@@ -0,0 +1,124 @@
+mod anomaly;
+mod generator;
+mod window;
+
+pub use crate::anomaly::{Anomalies, Anomaly, Detector, Reason};
+pub use crate::window::{Aggregation, Direction, Run, SlidingWindows, Trends, Windows};
+use solution::{ParseError, Solution};
+
+fn count_increases(values: &[u32]) -> u32 {
+    count_window_increases(values, 1)
+}
+
+fn count_window_increases(values: &[u32], size: usize) -> u32 {
+    let windows = values
+        .iter()
+        .copied()
+        .sliding_windows(size, Aggregation::Sum);
+    windows.collect::<Trends>().increases as u32
+}
+
+fn parse_depths(s: &str) -> Result<Vec<u32>, ParseError> {
+    s.lines()
+        .map(|line| line.parse().map_err(|_| ParseError::at(s, line, ["depth"])))
+        .collect()
+}
+
+/// Parses one line of input, which is the given line number, for reading depths as they arrive.
+pub fn parse_depth(line_number: usize, line: &str) -> Result<u32, ParseError> {
+    line.trim_end_matches(['\r', '\n'])
+        .parse()
+        .map_err(|_| ParseError::in_line(line_number, line, 0, ["depth"]))
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<u32>;
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_depths(input)
+    }
+
+    fn solve_part_a(depths: &Self::Input<'_>) -> Self::AnswerA {
+        count_increases(depths)
+    }
+
+    fn solve_part_b(depths: &Self::Input<'_>) -> Self::AnswerB {
+        count_window_increases(depths, 3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_count_increases() {
+        assert_eq!(count_increases(&[]), 0);
+        assert_eq!(count_increases(&[0, 1, 2]), 2);
+        assert_eq!(count_increases(&[0, 0, 0]), 0);
+        assert_eq!(count_increases(&[2, 1, 0]), 0);
+    }
+
+    #[test]
+    fn test_count_window_increases() {
+        assert_eq!(count_window_increases(&[], 2), 0);
+        assert_eq!(count_window_increases(&[1, 1, 1, 1], 2), 0);
+        assert_eq!(count_window_increases(&[1, 1, 2, 2], 2), 2);
+    }
+
+    #[test]
+    fn test_parse_depth() {
+        assert_eq!(parse_depth(1, "199\n"), Ok(199));
+        let error = parse_depth(3, "2x0").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+    }
+
+    #[test]
+    fn test_anomalies() {
+        let input = fs::read_to_string("input").unwrap();
+        let depths = Day01::parse(&input).unwrap();
+        let detector = Detector {
+            window: 5,
+            deviations: None,
+            jump: Some(30),
+        };
+
+        let anomalies: Vec<Anomaly> = detector.detect(depths.iter().copied()).collect();
+        assert_eq!(anomalies.len(), 46);
+
+        for anomaly in anomalies {
+            let line = input.lines().nth(anomaly.line - 1).unwrap();
+            assert_eq!(line.parse(), Ok(anomaly.depth));
+            assert!(matches!(anomaly.reasons[..], [Reason::Jump { .. }]));
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let generated = solution::generate::<Day01>(seed, 200);
+            let depths = Day01::parse(&generated.input).unwrap();
+            assert_eq!(depths.len(), 200);
+            assert_eq!(
+                Day01::solve_part_a(&depths),
+                generated.truth.unwrap()["increases"]
+            );
+
+            let increases = depths.windows(4).filter(|w| w[0] < w[3]).count();
+            assert_eq!(Day01::solve_part_b(&depths), increases as u32);
+        }
+    }
+
+    #[test]
+    fn test_example() {
+        let input = fs::read_to_string("input-test").unwrap();
+        let depths = Day01::parse(&input).unwrap();
+        assert_eq!(Day01::solve_part_a(&depths), 7);
+        assert_eq!(Day01::solve_part_b(&depths), 5);
+    }
+}
@@ -0,0 +1,297 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::str::FromStr;
+
+/// How the depths in a window are combined into one value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregation {
+    Sum,
+    Mean,
+    Min,
+    Max,
+    Median,
+}
+
+impl Aggregation {
+    pub const ALL: [Aggregation; 5] = [
+        Aggregation::Sum,
+        Aggregation::Mean,
+        Aggregation::Min,
+        Aggregation::Max,
+        Aggregation::Median,
+    ];
+
+    fn apply(&self, window: &VecDeque<u32>) -> f64 {
+        match self {
+            Aggregation::Sum => window.iter().map(|&depth| depth as f64).sum(),
+            Aggregation::Mean => Aggregation::Sum.apply(window) / window.len() as f64,
+            Aggregation::Min => *window.iter().min().unwrap() as f64,
+            Aggregation::Max => *window.iter().max().unwrap() as f64,
+            Aggregation::Median => {
+                let mut depths: Vec<u32> = window.iter().copied().collect();
+                depths.sort_unstable();
+
+                let middle = depths.len() / 2;
+                if depths.len().is_multiple_of(2) {
+                    (depths[middle - 1] as f64 + depths[middle] as f64) / 2.0
+                } else {
+                    depths[middle] as f64
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Aggregation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Aggregation::Sum => "sum",
+            Aggregation::Mean => "mean",
+            Aggregation::Min => "min",
+            Aggregation::Max => "max",
+            Aggregation::Median => "median",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for Aggregation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|aggregation| aggregation.to_string() == s)
+            .ok_or_else(|| format!("unknown aggregation: {}", s))
+    }
+}
+
+/// An iterator over the aggregate of every full window of depths, which only ever holds one
+/// window's worth of depths, so the depths can come from a source of any length.
+#[derive(Debug, Clone)]
+pub struct Windows<I> {
+    depths: I,
+    size: usize,
+    aggregation: Aggregation,
+    window: VecDeque<u32>,
+}
+
+impl<I: Iterator<Item = u32>> Iterator for Windows<I> {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.window.len() == self.size {
+            self.window.pop_front();
+        }
+
+        while self.window.len() < self.size {
+            self.window.push_back(self.depths.next()?);
+        }
+
+        Some(self.aggregation.apply(&self.window))
+    }
+}
+
+pub trait SlidingWindows: Iterator<Item = u32> + Sized {
+    /// Aggregates each window of `size` consecutive depths, like [`slice::windows`] does for
+    /// slices. Nothing is given if there are fewer depths than that.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    fn sliding_windows(self, size: usize, aggregation: Aggregation) -> Windows<Self> {
+        assert!(size > 0, "window size must be non-zero");
+
+        Windows {
+            depths: self,
+            size,
+            aggregation,
+            window: VecDeque::with_capacity(size),
+        }
+    }
+}
+
+impl<I: Iterator<Item = u32>> SlidingWindows for I {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Increasing,
+    Decreasing,
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Direction::Increasing => write!(f, "increasing"),
+            Direction::Decreasing => write!(f, "decreasing"),
+        }
+    }
+}
+
+/// A stretch of values that only ever increase or only ever decrease.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    pub direction: Direction,
+    /// The index of the first value.
+    pub start: usize,
+    /// The number of values, which is always at least two.
+    pub length: usize,
+}
+
+/// How each value compares to the one before it, gathered one value at a time.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Trends {
+    pub count: usize,
+    pub increases: usize,
+    pub decreases: usize,
+    pub plateaus: usize,
+    /// The earliest of the longest runs, if any two values differed.
+    pub longest_run: Option<Run>,
+    previous: Option<f64>,
+    current_run: Option<Run>,
+}
+
+impl Trends {
+    pub fn push(&mut self, value: f64) {
+        let index = self.count;
+        self.count += 1;
+
+        let previous = match self.previous.replace(value) {
+            Some(previous) => previous,
+            None => return,
+        };
+
+        let direction = if value > previous {
+            self.increases += 1;
+            Direction::Increasing
+        } else if value < previous {
+            self.decreases += 1;
+            Direction::Decreasing
+        } else {
+            self.plateaus += 1;
+            self.current_run = None;
+            return;
+        };
+
+        let run = match self.current_run {
+            Some(run) if run.direction == direction => Run {
+                length: run.length + 1,
+                ..run
+            },
+            // A run that changes direction starts again from the value where it turned.
+            _ => Run {
+                direction,
+                start: index - 1,
+                length: 2,
+            },
+        };
+
+        if self
+            .longest_run
+            .is_none_or(|longest| run.length > longest.length)
+        {
+            self.longest_run = Some(run);
+        }
+
+        self.current_run = Some(run);
+    }
+}
+
+impl Extend<f64> for Trends {
+    fn extend<I: IntoIterator<Item = f64>>(&mut self, values: I) {
+        for value in values {
+            self.push(value);
+        }
+    }
+}
+
+impl FromIterator<f64> for Trends {
+    fn from_iter<I: IntoIterator<Item = f64>>(values: I) -> Self {
+        let mut trends: Self = Default::default();
+        trends.extend(values);
+        trends
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aggregate(depths: &[u32], size: usize, aggregation: Aggregation) -> Vec<f64> {
+        depths
+            .iter()
+            .copied()
+            .sliding_windows(size, aggregation)
+            .collect()
+    }
+
+    #[test]
+    fn test_sliding_windows() {
+        let depths = [3, 1, 4, 1, 5, 9];
+        assert_eq!(
+            aggregate(&depths, 3, Aggregation::Sum),
+            [8.0, 6.0, 10.0, 15.0]
+        );
+        assert_eq!(
+            aggregate(&depths, 2, Aggregation::Mean),
+            [2.0, 2.5, 2.5, 3.0, 7.0]
+        );
+        assert_eq!(
+            aggregate(&depths, 3, Aggregation::Min),
+            [1.0, 1.0, 1.0, 1.0]
+        );
+        assert_eq!(
+            aggregate(&depths, 3, Aggregation::Max),
+            [4.0, 4.0, 5.0, 9.0]
+        );
+        assert_eq!(aggregate(&depths, 4, Aggregation::Median), [2.0, 2.5, 4.5]);
+        assert_eq!(aggregate(&depths, 1, Aggregation::Sum).len(), 6);
+        assert!(aggregate(&depths, 7, Aggregation::Sum).is_empty());
+    }
+
+    #[test]
+    fn test_sliding_windows_unbounded() {
+        let windows = (0..).sliding_windows(2, Aggregation::Max);
+        assert_eq!(windows.take(3).collect::<Vec<_>>(), [1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_aggregation_from_str() {
+        for aggregation in Aggregation::ALL {
+            assert_eq!(aggregation.to_string().parse(), Ok(aggregation));
+        }
+        assert!("average".parse::<Aggregation>().is_err());
+    }
+
+    #[test]
+    fn test_trends() {
+        let trends: Trends = [1.0, 2.0, 2.0, 3.0, 4.0, 5.0, 1.0, 0.0]
+            .into_iter()
+            .collect();
+        assert_eq!(trends.count, 8);
+        assert_eq!(trends.increases, 4);
+        assert_eq!(trends.decreases, 2);
+        assert_eq!(trends.plateaus, 1);
+        assert_eq!(
+            trends.longest_run,
+            Some(Run {
+                direction: Direction::Increasing,
+                start: 2,
+                length: 4
+            })
+        );
+
+        let trends: Trends = [5.0, 4.0, 6.0, 3.0].into_iter().collect();
+        assert_eq!(
+            trends.longest_run,
+            Some(Run {
+                direction: Direction::Decreasing,
+                start: 0,
+                length: 2
+            })
+        );
+
+        let trends: Trends = [7.0, 7.0].into_iter().collect();
+        assert_eq!((trends.plateaus, trends.longest_run), (1, None));
+    }
+}
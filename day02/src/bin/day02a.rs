@@ -0,0 +1,15 @@
+use day02::Day02;
+use solution::Solution;
+use std::fs;
+use std::process;
+
+fn main() {
+    let input = fs::read_to_string("input").unwrap();
+    match Day02::parse(&input) {
+        Ok(parsed) => println!("{}", Day02::solve_part_a(&parsed)),
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::Day02;
+use rand::Rng;
+use serde_json::json;
+use solution::{Generated, Generator};
+
+/// Plain movements, like the real input has, where the size is the number of them. The
+/// submarine never goes above the surface. The truth is where it ends up with and without aim.
+impl Generator for Day02 {
+    const DEFAULT_SIZE: usize = 1000;
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+        let mut input = String::new();
+        let (mut distance, mut depth) = (0, 0);
+        let (mut aimed_depth, mut aim) = (0, 0);
+
+        for _ in 0..size.max(1) {
+            let magnitude: i64 = rng.gen_range(1..=9);
+
+            let direction = match rng.gen_range(0..3) {
+                0 => {
+                    distance += magnitude;
+                    aimed_depth += aim * magnitude;
+                    "forward"
+                }
+                1 => {
+                    depth += magnitude;
+                    aim += magnitude;
+                    "down"
+                }
+                // The aim always matches the plain depth, so keeping that below the surface keeps
+                // the aimed depth there too.
+                _ if depth >= magnitude => {
+                    depth -= magnitude;
+                    aim -= magnitude;
+                    "up"
+                }
+                _ => {
+                    depth += magnitude;
+                    aim += magnitude;
+                    "down"
+                }
+            };
+
+            input += &format!("{} {}\n", direction, magnitude);
+        }
+
+        Generated {
+            input,
+            truth: Some(json!({
+                "distance": distance,
+                "depth": depth,
+                "aimed_depth": aimed_depth,
+            })),
+        }
+    }
+}
@@ -0,0 +1,346 @@
+mod generator;
+mod model;
+mod planner;
+mod script;
+
+pub use crate::model::{
+    AimedPosition, Coordinates, Model, OrientedPosition, Position, SubmarineModel, Surfacing,
+};
+pub use crate::planner::plan_route;
+use crate::script::{command_parser, line_starts, Interpreter, Step};
+pub use crate::script::{format_script, Command, ScriptError, ScriptErrorKind, Statement};
+use solution::{ParseError, Solution};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    Forward,
+    Down,
+    Up,
+    Left,
+    Right,
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Direction::Forward => write!(f, "forward"),
+            Direction::Down => write!(f, "down"),
+            Direction::Up => write!(f, "up"),
+            Direction::Left => write!(f, "left"),
+            Direction::Right => write!(f, "right"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Movement {
+    direction: Direction,
+    magnitude: usize,
+}
+
+impl fmt::Display for Movement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.direction, self.magnitude)
+    }
+}
+
+fn steer(
+    submarine: &mut dyn SubmarineModel,
+    statements: &[Statement],
+    surfacing: Surfacing,
+) -> Result<(), ScriptError> {
+    Interpreter::default().run(statements, &mut |step| {
+        match step {
+            Step::Move(movement) => submarine.apply(movement)?,
+            Step::Reset => submarine.reset(),
+        }
+
+        if submarine.coordinates().depth < 0 {
+            match surfacing {
+                Surfacing::Clamp => submarine.surface(),
+                Surfacing::Error => return Err(ScriptErrorKind::Surfaced),
+                Surfacing::Allow => {}
+            }
+        }
+
+        Ok(())
+    })
+}
+
+fn get_position<P>(
+    statements: &[Statement],
+    surfacing: Surfacing,
+) -> Result<Coordinates, ScriptError>
+where
+    P: SubmarineModel + Default,
+{
+    let mut submarine = P::default();
+    steer(&mut submarine, statements, surfacing)?;
+    Ok(submarine.coordinates())
+}
+
+/// Runs a script with the model chosen at runtime, so the same script can be compared across all
+/// of them. The error for a submarine that goes out of range or above the surface says which
+/// statement took it there.
+pub fn evaluate(
+    statements: &[Statement],
+    model: Model,
+    surfacing: Surfacing,
+) -> Result<Coordinates, ScriptError> {
+    let mut submarine = model.create();
+    steer(submarine.as_mut(), statements, surfacing)?;
+    Ok(submarine.coordinates())
+}
+
+// Points at the statement that failed, as though it couldn't be parsed.
+fn get_parse_error(s: &str, error: ScriptError) -> ParseError {
+    let line = &s[line_starts(s)[error.line - 1]..];
+    let indent = line.len() - line.trim_start().len();
+    ParseError::at(s, &line[indent..], [error.expected()])
+}
+
+/// Parses a script and checks it for the errors that any submarine would hit, which leaves the ones
+/// that depend on how the submarine moves to be reported by [`evaluate`] for each model.
+pub fn parse_script(s: &str) -> Result<Vec<Statement>, ParseError> {
+    let statements = command_parser::parse(s, &line_starts(s))
+        .map_err(|error| ParseError::from_peg(s, error))?;
+
+    Interpreter::check(&statements).map_err(|error| get_parse_error(s, error))?;
+
+    Ok(statements)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<Statement>;
+    type AnswerA = i128;
+    type AnswerB = i128;
+
+    // The answers can't be errors, so a script that either part's submarine couldn't follow, like
+    // one that dives too deep to hold, is rejected here instead.
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let statements = parse_script(input)?;
+
+        get_position::<Position>(&statements, Default::default())
+            .and(get_position::<AimedPosition>(
+                &statements,
+                Default::default(),
+            ))
+            .map_err(|error| get_parse_error(input, error))?;
+
+        Ok(statements)
+    }
+
+    fn solve_part_a(statements: &Self::Input<'_>) -> Self::AnswerA {
+        get_position::<Position>(statements, Default::default())
+            .expect("scripts are run while they're parsed")
+            .product()
+    }
+
+    fn solve_part_b(statements: &Self::Input<'_>) -> Self::AnswerB {
+        get_position::<AimedPosition>(statements, Default::default())
+            .expect("scripts are run while they're parsed")
+            .product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_example() {
+        let input = fs::read_to_string("input-test").unwrap();
+        let commands = Day02::parse(&input).unwrap();
+        assert_eq!(Day02::solve_part_a(&commands), 150);
+        assert_eq!(Day02::solve_part_b(&commands), 900);
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let generated = solution::generate::<Day02>(seed, 100);
+            let statements = Day02::parse(&generated.input).unwrap();
+            let truth = generated.truth.unwrap();
+            let (distance, depth, aimed_depth) = (
+                truth["distance"].as_i64().unwrap() as i128,
+                truth["depth"].as_i64().unwrap() as i128,
+                truth["aimed_depth"].as_i64().unwrap() as i128,
+            );
+
+            assert_eq!(Day02::solve_part_a(&statements), distance * depth);
+            assert_eq!(Day02::solve_part_b(&statements), distance * aimed_depth);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day02::parse("forward 5\nsideways 3\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert!(error.expected.contains(&"\"forward\"".to_string()));
+        assert_eq!(error.snippet, "  |\n2 | sideways 3\n  | ^");
+
+        let error = Day02::parse("up 99999999999999999999999\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 27));
+        assert!(error.expected.contains(&"magnitude".to_string()));
+
+        let error = Day02::parse("forward 5\nrepeat 2 {\n  call dive\n}\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 3));
+        assert_eq!(
+            error.expected,
+            vec!["a call to a macro defined before this line, not `dive`"]
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        // Fine for part a, but part b's depth goes out of range on the second line.
+        let input = "down 9223372036854775807\nforward 2\n";
+        let error = Day02::parse(input).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(
+            error.expected,
+            vec!["a movement that keeps the submarine in range"]
+        );
+
+        let statements = Day02::parse("down 9223372036854775807\nforward 1\n").unwrap();
+        assert_eq!(Day02::solve_part_b(&statements), 9223372036854775807);
+    }
+
+    #[test]
+    fn test_script() {
+        let script = "\
+            # The example, with the repetition taken out.\n\
+            macro dive {\n\
+                down 5\n\
+                forward 8\n\
+            }\n\
+            \n\
+            forward 5\n\
+            call dive\n\
+            up 3\n\
+            down 8\n\
+            forward 2\n";
+        let statements = Day02::parse(script).unwrap();
+        assert_eq!(Day02::solve_part_a(&statements), 150);
+        assert_eq!(Day02::solve_part_b(&statements), 900);
+
+        let statements = Day02::parse("repeat 3 {\n  down 2\n  forward 1\n}\n").unwrap();
+        assert_eq!(
+            get_position::<Position>(&statements, Surfacing::Allow),
+            Ok(Coordinates {
+                distance: 3,
+                depth: 6,
+                drift: 0
+            })
+        );
+
+        let statements = Day02::parse("forward 2\ndown 2\nreset\nforward 1\n").unwrap();
+        assert_eq!(
+            get_position::<Position>(&statements, Surfacing::Allow),
+            Ok(Coordinates {
+                distance: 1,
+                depth: 0,
+                drift: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let input = fs::read_to_string("input-test").unwrap();
+        let statements = Day02::parse(&input).unwrap();
+        let products: Vec<i128> = Model::ALL
+            .iter()
+            .map(|&model| {
+                evaluate(&statements, model, Surfacing::Allow)
+                    .unwrap()
+                    .product()
+            })
+            .collect();
+        assert_eq!(products, vec![150, 900, 900]);
+
+        let statements = Day02::parse(
+            "down 1
+right 2
+forward 3
+left 4
+forward 1
+",
+        )
+        .unwrap();
+        assert_eq!(
+            evaluate(&statements, Model::Oriented, Surfacing::Allow),
+            Ok(Coordinates {
+                distance: 4,
+                depth: 4,
+                drift: 4
+            })
+        );
+        assert_eq!(
+            evaluate(&statements, Model::Aimed, Surfacing::Allow),
+            Ok(Coordinates {
+                distance: 4,
+                depth: 4,
+                drift: 0
+            })
+        );
+        assert_eq!(
+            evaluate(&statements, Model::Direct, Surfacing::Allow),
+            Ok(Coordinates {
+                distance: 4,
+                depth: 1,
+                drift: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_surfacing() {
+        let statements = Day02::parse(
+            "down 2
+repeat 3 {
+  up 1
+}
+forward 1
+",
+        )
+        .unwrap();
+
+        assert_eq!(
+            evaluate(&statements, Model::Direct, Surfacing::Allow),
+            Ok(Coordinates {
+                distance: 1,
+                depth: -1,
+                drift: 0
+            })
+        );
+        assert_eq!(
+            evaluate(&statements, Model::Direct, Surfacing::Clamp),
+            Ok(Coordinates {
+                distance: 1,
+                depth: 0,
+                drift: 0
+            })
+        );
+
+        let error = evaluate(&statements, Model::Direct, Surfacing::Error).unwrap_err();
+        assert_eq!((error.index, error.line), (4, 3));
+        assert_eq!(error.kind, ScriptErrorKind::Surfaced);
+        assert_eq!(
+            error.to_string(),
+            "command 4 (line 3): the submarine went above the surface"
+        );
+
+        // Aiming upwards is fine, until the submarine follows its aim out of the water.
+        let error = evaluate(&statements, Model::Aimed, Surfacing::Error).unwrap_err();
+        assert_eq!((error.index, error.line), (5, 5));
+
+        let statements = parse_script("down 9223372036854775807\nforward 2\n").unwrap();
+        let error = evaluate(&statements, Model::Aimed, Surfacing::Allow).unwrap_err();
+        assert_eq!((error.index, error.kind), (1, ScriptErrorKind::Overflow));
+    }
+}
@@ -0,0 +1,501 @@
+use crate::{Direction, Movement, ScriptErrorKind};
+use std::fmt;
+use std::str::FromStr;
+
+/// Where a submarine is, relative to where it started. A negative depth is above the surface.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Coordinates {
+    pub distance: isize,
+    pub depth: isize,
+    /// How far to the right of its starting line the submarine has drifted, or to the left if
+    /// it's negative.
+    pub drift: isize,
+}
+
+impl Coordinates {
+    /// The puzzle's answer, which ignores any drift. It's wide enough that it can't overflow.
+    pub fn product(&self) -> i128 {
+        self.distance as i128 * self.depth as i128
+    }
+}
+
+/// How a submarine responds to each movement. Every model starts at the surface, level and facing
+/// forward.
+pub trait SubmarineModel {
+    /// Fails without moving if any part of the submarine's state would overflow.
+    fn apply(&mut self, movement: Movement) -> Result<(), ScriptErrorKind>;
+
+    /// Brings the submarine back up to the surface, if it went above it, without changing anything
+    /// else about it.
+    fn surface(&mut self);
+
+    /// Goes back to the start.
+    fn reset(&mut self);
+
+    fn coordinates(&self) -> Coordinates;
+}
+
+/// What to do when a submarine goes above the surface.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Surfacing {
+    /// Stops it at the surface.
+    Clamp,
+    /// Stops running the script.
+    Error,
+    /// Lets it keep going, with a negative depth.
+    #[default]
+    Allow,
+}
+
+impl Surfacing {
+    pub const ALL: [Surfacing; 3] = [Surfacing::Clamp, Surfacing::Error, Surfacing::Allow];
+}
+
+impl fmt::Display for Surfacing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Surfacing::Clamp => write!(f, "clamp"),
+            Surfacing::Error => write!(f, "error"),
+            Surfacing::Allow => write!(f, "allow"),
+        }
+    }
+}
+
+impl FromStr for Surfacing {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|surfacing| surfacing.to_string() == s)
+            .ok_or_else(|| format!("unknown surfacing policy: {}", s))
+    }
+}
+
+// The magnitude of a movement, as something that can be added to a coordinate.
+fn signed(movement: Movement) -> Option<isize> {
+    isize::try_from(movement.magnitude).ok()
+}
+
+/// Moving up and down changes the depth directly. Turning does nothing, since there's no way to
+/// steer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Position {
+    distance: isize,
+    depth: isize,
+}
+
+impl Position {
+    pub fn checked_add(self, movement: Movement) -> Option<Self> {
+        let magnitude = signed(movement)?;
+
+        Some(match movement.direction {
+            Direction::Forward => Self {
+                distance: self.distance.checked_add(magnitude)?,
+                ..self
+            },
+            Direction::Down => Self {
+                depth: self.depth.checked_add(magnitude)?,
+                ..self
+            },
+            Direction::Up => Self {
+                depth: self.depth.checked_sub(magnitude)?,
+                ..self
+            },
+            Direction::Left | Direction::Right => self,
+        })
+    }
+}
+
+/// Moving up and down changes the aim, which is how much deeper each step forward goes. Turning
+/// does nothing, since there's no way to steer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct AimedPosition {
+    distance: isize,
+    depth: isize,
+    aim: isize,
+}
+
+impl AimedPosition {
+    pub fn new(distance: isize, depth: isize, aim: isize) -> Self {
+        Self {
+            distance,
+            depth,
+            aim,
+        }
+    }
+
+    pub fn distance(&self) -> isize {
+        self.distance
+    }
+
+    pub fn depth(&self) -> isize {
+        self.depth
+    }
+
+    pub fn aim(&self) -> isize {
+        self.aim
+    }
+
+    pub fn checked_add(self, movement: Movement) -> Option<Self> {
+        let magnitude = signed(movement)?;
+
+        Some(match movement.direction {
+            Direction::Forward => Self {
+                distance: self.distance.checked_add(magnitude)?,
+                depth: self.depth.checked_add(self.aim.checked_mul(magnitude)?)?,
+                ..self
+            },
+            Direction::Down => Self {
+                aim: self.aim.checked_add(magnitude)?,
+                ..self
+            },
+            Direction::Up => Self {
+                aim: self.aim.checked_sub(magnitude)?,
+                ..self
+            },
+            Direction::Left | Direction::Right => self,
+        })
+    }
+}
+
+/// Aims in three dimensions. Moving up and down changes the pitch, which is how much deeper each
+/// step forward goes, and turning left and right changes the yaw, which is how much farther to the
+/// right each step forward goes. Without any turns, it's the same as [`AimedPosition`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct OrientedPosition {
+    distance: isize,
+    depth: isize,
+    drift: isize,
+    pitch: isize,
+    yaw: isize,
+}
+
+impl OrientedPosition {
+    pub fn checked_add(self, movement: Movement) -> Option<Self> {
+        let magnitude = signed(movement)?;
+
+        Some(match movement.direction {
+            Direction::Forward => Self {
+                distance: self.distance.checked_add(magnitude)?,
+                depth: self.depth.checked_add(self.pitch.checked_mul(magnitude)?)?,
+                drift: self.drift.checked_add(self.yaw.checked_mul(magnitude)?)?,
+                ..self
+            },
+            Direction::Down => Self {
+                pitch: self.pitch.checked_add(magnitude)?,
+                ..self
+            },
+            Direction::Up => Self {
+                pitch: self.pitch.checked_sub(magnitude)?,
+                ..self
+            },
+            Direction::Right => Self {
+                yaw: self.yaw.checked_add(magnitude)?,
+                ..self
+            },
+            Direction::Left => Self {
+                yaw: self.yaw.checked_sub(magnitude)?,
+                ..self
+            },
+        })
+    }
+}
+
+impl SubmarineModel for Position {
+    fn apply(&mut self, movement: Movement) -> Result<(), ScriptErrorKind> {
+        *self = self
+            .checked_add(movement)
+            .ok_or(ScriptErrorKind::Overflow)?;
+        Ok(())
+    }
+
+    fn surface(&mut self) {
+        self.depth = self.depth.max(0);
+    }
+
+    fn reset(&mut self) {
+        *self = Default::default();
+    }
+
+    fn coordinates(&self) -> Coordinates {
+        Coordinates {
+            distance: self.distance,
+            depth: self.depth,
+            drift: 0,
+        }
+    }
+}
+
+impl SubmarineModel for AimedPosition {
+    fn apply(&mut self, movement: Movement) -> Result<(), ScriptErrorKind> {
+        *self = self
+            .checked_add(movement)
+            .ok_or(ScriptErrorKind::Overflow)?;
+        Ok(())
+    }
+
+    fn surface(&mut self) {
+        self.depth = self.depth.max(0);
+    }
+
+    fn reset(&mut self) {
+        *self = Default::default();
+    }
+
+    fn coordinates(&self) -> Coordinates {
+        Coordinates {
+            distance: self.distance,
+            depth: self.depth,
+            drift: 0,
+        }
+    }
+}
+
+impl SubmarineModel for OrientedPosition {
+    fn apply(&mut self, movement: Movement) -> Result<(), ScriptErrorKind> {
+        *self = self
+            .checked_add(movement)
+            .ok_or(ScriptErrorKind::Overflow)?;
+        Ok(())
+    }
+
+    fn surface(&mut self) {
+        self.depth = self.depth.max(0);
+    }
+
+    fn reset(&mut self) {
+        *self = Default::default();
+    }
+
+    fn coordinates(&self) -> Coordinates {
+        Coordinates {
+            distance: self.distance,
+            depth: self.depth,
+            drift: self.drift,
+        }
+    }
+}
+
+/// The models that can be chosen while the program is running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Model {
+    Direct,
+    Aimed,
+    Oriented,
+}
+
+impl Model {
+    pub const ALL: [Model; 3] = [Model::Direct, Model::Aimed, Model::Oriented];
+
+    pub fn create(&self) -> Box<dyn SubmarineModel> {
+        match self {
+            Model::Direct => Box::new(Position::default()),
+            Model::Aimed => Box::new(AimedPosition::default()),
+            Model::Oriented => Box::new(OrientedPosition::default()),
+        }
+    }
+}
+
+impl fmt::Display for Model {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Model::Direct => write!(f, "direct"),
+            Model::Aimed => write!(f, "aimed"),
+            Model::Oriented => write!(f, "oriented"),
+        }
+    }
+}
+
+impl FromStr for Model {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|model| model.to_string() == s)
+            .ok_or_else(|| format!("unknown model: {}", s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn movement(direction: Direction, magnitude: usize) -> Movement {
+        Movement {
+            direction,
+            magnitude,
+        }
+    }
+
+    #[test]
+    fn test_position_add() {
+        let position = Position {
+            distance: 1,
+            depth: 2,
+        };
+        assert_eq!(
+            position.checked_add(movement(Direction::Forward, 5)),
+            Some(Position {
+                distance: 6,
+                depth: 2
+            })
+        );
+        assert_eq!(
+            position.checked_add(movement(Direction::Down, 5)),
+            Some(Position {
+                distance: 1,
+                depth: 7
+            })
+        );
+        assert_eq!(
+            position.checked_add(movement(Direction::Up, 5)),
+            Some(Position {
+                distance: 1,
+                depth: -3
+            })
+        );
+        assert_eq!(
+            position.checked_add(movement(Direction::Forward, usize::MAX)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_aimed_position_add() {
+        let position = AimedPosition {
+            distance: 1,
+            depth: 2,
+            aim: 3,
+        };
+        assert_eq!(
+            position.checked_add(movement(Direction::Down, 1)),
+            Some(AimedPosition { aim: 4, ..position })
+        );
+        assert_eq!(
+            position.checked_add(movement(Direction::Up, 5)),
+            Some(AimedPosition {
+                aim: -2,
+                ..position
+            })
+        );
+        assert_eq!(
+            position.checked_add(movement(Direction::Forward, 5)),
+            Some(AimedPosition {
+                distance: 6,
+                depth: 17,
+                aim: 3
+            })
+        );
+        assert_eq!(
+            AimedPosition {
+                aim: isize::MAX / 2,
+                ..position
+            }
+            .checked_add(movement(Direction::Forward, 3)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_oriented_position_add() {
+        let position = OrientedPosition {
+            distance: 1,
+            depth: 2,
+            drift: 3,
+            pitch: 4,
+            yaw: -5,
+        };
+        assert_eq!(
+            position.checked_add(movement(Direction::Forward, 2)),
+            Some(OrientedPosition {
+                distance: 3,
+                depth: 10,
+                drift: -7,
+                ..position
+            })
+        );
+        assert_eq!(
+            position.checked_add(movement(Direction::Left, 2)),
+            Some(OrientedPosition {
+                yaw: -7,
+                ..position
+            })
+        );
+        assert_eq!(
+            position.checked_add(movement(Direction::Right, 6)),
+            Some(OrientedPosition { yaw: 1, ..position })
+        );
+        assert_eq!(
+            position.checked_add(movement(Direction::Up, 6)),
+            Some(OrientedPosition {
+                pitch: -2,
+                ..position
+            })
+        );
+    }
+
+    #[test]
+    fn test_models() {
+        let movements = [
+            movement(Direction::Down, 2),
+            movement(Direction::Right, 1),
+            movement(Direction::Forward, 3),
+        ];
+
+        let coordinates: Vec<Coordinates> = Model::ALL
+            .iter()
+            .map(|model| {
+                let mut submarine = model.create();
+                for &movement in movements.iter() {
+                    submarine.apply(movement).unwrap();
+                }
+                submarine.coordinates()
+            })
+            .collect();
+
+        assert_eq!(
+            coordinates,
+            vec![
+                Coordinates {
+                    distance: 3,
+                    depth: 2,
+                    drift: 0
+                },
+                Coordinates {
+                    distance: 3,
+                    depth: 6,
+                    drift: 0
+                },
+                Coordinates {
+                    distance: 3,
+                    depth: 6,
+                    drift: 3
+                },
+            ]
+        );
+
+        for model in Model::ALL {
+            assert_eq!(model.to_string().parse(), Ok(model));
+        }
+        assert!("flat".parse::<Model>().is_err());
+
+        for surfacing in Surfacing::ALL {
+            assert_eq!(surfacing.to_string().parse(), Ok(surfacing));
+        }
+    }
+
+    #[test]
+    fn test_apply_overflow() {
+        let mut submarine = Position::default();
+        submarine
+            .apply(movement(Direction::Forward, isize::MAX as usize))
+            .unwrap();
+        assert_eq!(
+            submarine.apply(movement(Direction::Forward, 1)),
+            Err(ScriptErrorKind::Overflow)
+        );
+        assert_eq!(submarine.coordinates().distance, isize::MAX);
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{AimedPosition, Command, Direction, Movement};
+
+fn movement(direction: Direction, magnitude: usize) -> Command {
+    Command::Move(Movement {
+        direction,
+        magnitude,
+    })
+}
+
+// Turns the aim from one value to another, which can be done in one movement as long as they
+// differ.
+fn turn(from: isize, to: isize) -> Option<Command> {
+    let direction = if to > from {
+        Direction::Down
+    } else if to < from {
+        Direction::Up
+    } else {
+        return None;
+    };
+
+    Some(movement(direction, to.abs_diff(from)))
+}
+
+/// Finds the shortest list of movements that takes a submarine with aim from the start to the
+/// target, if there's any way to get there at all. The submarine can never go backwards, and it
+/// can't change depth without moving forward.
+///
+/// Any two movements in a row that both move forward, or both change the aim, could be replaced by
+/// one, so a shortest route alternates between them. The depth is the sum of each forward movement
+/// times the aim during it, and since two forward movements are always enough to reach any depth
+/// (with the first one at an aim of zero, then the second at the depth that's still needed), a
+/// route never needs more than four movements. Targets so far out that working out their route
+/// would overflow have no route either.
+pub fn plan_route(target: AimedPosition) -> Option<Vec<Command>> {
+    let (distance, depth, aim) = (target.distance(), target.depth(), target.aim());
+
+    // The aims to hold during each forward movement, along with how far each of them goes.
+    let legs: Vec<(isize, isize)> = if distance < 0 || (distance == 0 && depth != 0) {
+        return None;
+    } else if distance == 0 {
+        vec![]
+    } else if depth % distance == 0 {
+        vec![(depth / distance, distance)]
+    } else if aim != 0
+        && depth.checked_rem(aim)? == 0
+        && (1..distance).contains(&depth.checked_div(aim)?)
+    {
+        // The target's aim can be reached first and then held, with the rest done flat.
+        vec![(0, distance - depth / aim), (aim, depth / aim)]
+    } else {
+        vec![(0, distance - 1), (depth, 1)]
+    };
+
+    let mut route: Vec<Command> = Default::default();
+    let mut current = 0;
+
+    for (leg_aim, length) in legs {
+        route.extend(turn(current, leg_aim));
+        route.push(movement(Direction::Forward, length as usize));
+        current = leg_aim;
+    }
+
+    route.extend(turn(current, aim));
+
+    Some(route)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{format_script, parse_script, steer, Surfacing};
+    use std::collections::{HashMap, VecDeque};
+
+    fn follow(script: &str) -> AimedPosition {
+        let statements = parse_script(script).unwrap();
+        let mut submarine = AimedPosition::default();
+        steer(&mut submarine, &statements, Surfacing::Allow).unwrap();
+        submarine
+    }
+
+    // The fewest movements it takes to reach every position in a small box, found by trying every
+    // movement from every position reached so far, up to a certain magnitude.
+    fn shortest_routes(limit: isize) -> HashMap<AimedPosition, usize> {
+        let mut lengths: HashMap<AimedPosition, usize> = Default::default();
+        let mut queue: VecDeque<AimedPosition> = Default::default();
+
+        let start = AimedPosition::default();
+        lengths.insert(start, 0);
+        queue.push_back(start);
+
+        while let Some(position) = queue.pop_front() {
+            let length = lengths[&position];
+
+            for direction in [Direction::Forward, Direction::Down, Direction::Up] {
+                for magnitude in 1..=(limit * 4) as usize {
+                    let next = match position.checked_add(Movement {
+                        direction,
+                        magnitude,
+                    }) {
+                        Some(next) => next,
+                        None => continue,
+                    };
+
+                    let within = next.distance() <= limit
+                        && next.depth().abs() <= limit * limit
+                        && next.aim().abs() <= limit * 4;
+
+                    if within && !lengths.contains_key(&next) {
+                        lengths.insert(next, length + 1);
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+
+        lengths
+    }
+
+    #[test]
+    fn test_plan_route() {
+        let target = AimedPosition::new(15, 60, 10);
+        let route = plan_route(target).unwrap();
+        assert_eq!(format_script(&route), "down 4\nforward 15\ndown 6\n");
+        assert_eq!(follow(&format_script(&route)), target);
+
+        let route = plan_route(AimedPosition::new(7, 5, 1)).unwrap();
+        assert_eq!(format_script(&route), "forward 2\ndown 1\nforward 5\n");
+
+        let route = plan_route(AimedPosition::new(7, -5, 2)).unwrap();
+        assert_eq!(
+            format_script(&route),
+            "forward 6\nup 5\nforward 1\ndown 7\n"
+        );
+
+        assert_eq!(plan_route(AimedPosition::default()), Some(vec![]));
+        assert_eq!(plan_route(AimedPosition::new(0, 3, 0)), None);
+        assert_eq!(plan_route(AimedPosition::new(-1, 0, 0)), None);
+        assert_eq!(plan_route(AimedPosition::new(5, isize::MIN, -1)), None);
+    }
+
+    #[test]
+    fn test_plan_route_against_search() {
+        let limit = 5;
+        let lengths = shortest_routes(limit);
+
+        for distance in 0..=limit {
+            for depth in -limit * 2..=limit * 2 {
+                for aim in -limit..=limit {
+                    let target = AimedPosition::new(distance, depth, aim);
+                    let route = plan_route(target);
+                    assert_eq!(route.as_ref().map(Vec::len), lengths.get(&target).copied());
+
+                    if let Some(route) = route {
+                        assert_eq!(follow(&format_script(&route)), target);
+                    }
+                }
+            }
+        }
+    }
+}
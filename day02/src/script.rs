@@ -0,0 +1,502 @@
+use crate::{Direction, Movement};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Move(Movement),
+    /// Runs the body the given number of times.
+    Repeat(usize, Vec<Statement>),
+    /// Names the body, so it can be called later on.
+    Macro(String, Vec<Statement>),
+    Call(String),
+    /// Puts the submarine back where it started.
+    Reset,
+}
+
+/// A command along with the line of the script it came from, starting at 1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statement {
+    pub line: usize,
+    pub command: Command,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScriptErrorKind {
+    UndefinedMacro(String),
+    RecursiveMacro(String),
+    /// Part of the submarine's state got too big to hold.
+    Overflow,
+    /// The submarine went above the surface, which it was told not to do.
+    Surfaced,
+    /// The script ran for more steps than the interpreter allows.
+    TooManySteps(usize),
+}
+
+/// A failure to run a script, pointing at the statement that caused it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptError {
+    /// How many statements ran before this one, counting each time a loop or macro goes through
+    /// its body.
+    pub index: usize,
+    pub line: usize,
+    pub kind: ScriptErrorKind,
+}
+
+impl ScriptError {
+    /// What the statement should have been instead, in the terms used by parse errors.
+    pub fn expected(&self) -> String {
+        match &self.kind {
+            ScriptErrorKind::UndefinedMacro(name) => {
+                format!("a call to a macro defined before this line, not `{}`", name)
+            }
+            ScriptErrorKind::RecursiveMacro(name) => {
+                format!(
+                    "a call to a macro other than `{}`, which is already running",
+                    name
+                )
+            }
+            ScriptErrorKind::Overflow => "a movement that keeps the submarine in range".to_string(),
+            ScriptErrorKind::Surfaced => {
+                "a movement that keeps the submarine below the surface".to_string()
+            }
+            ScriptErrorKind::TooManySteps(limit) => {
+                format!("a script that runs at most {} steps", limit)
+            }
+        }
+    }
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "command {} (line {}): ", self.index, self.line)?;
+
+        match &self.kind {
+            ScriptErrorKind::UndefinedMacro(name) => write!(f, "macro `{}` is not defined", name),
+            ScriptErrorKind::RecursiveMacro(name) => write!(f, "macro `{}` calls itself", name),
+            ScriptErrorKind::Overflow => write!(f, "the submarine went out of range"),
+            ScriptErrorKind::Surfaced => write!(f, "the submarine went above the surface"),
+            ScriptErrorKind::TooManySteps(limit) => {
+                write!(f, "the script ran for more than {} steps", limit)
+            }
+        }
+    }
+}
+
+impl Error for ScriptError {}
+
+/// What running a script does to the submarine, one step at a time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Step {
+    Move(Movement),
+    Reset,
+}
+
+/// The most steps a script can take by default, where each statement and each time through a loop
+/// is a step.
+pub const STEP_LIMIT: usize = 100_000_000;
+
+/// Walks the statements of a script, handing each step to a callback, which can stop the script by
+/// failing. Macros are defined when their statement is reached, so they can only be called after
+/// that.
+#[derive(Debug)]
+pub struct Interpreter<'a> {
+    macros: HashMap<&'a str, &'a [Statement]>,
+    calls: Vec<&'a str>,
+    index: usize,
+    // Loops count as steps along with statements, so that looping over nothing still stops.
+    steps: usize,
+    limit: usize,
+    // Checking a script only needs to run each loop once, since every iteration does the same.
+    checking: bool,
+}
+
+impl<'a> Default for Interpreter<'a> {
+    fn default() -> Self {
+        Self::with_limit(STEP_LIMIT)
+    }
+}
+
+impl<'a> Interpreter<'a> {
+    pub fn with_limit(limit: usize) -> Self {
+        Self {
+            macros: Default::default(),
+            calls: Default::default(),
+            index: 0,
+            steps: 0,
+            limit,
+            checking: false,
+        }
+    }
+
+    /// Finds the errors that running the script would hit, without running any of its loops more
+    /// than once. Loops that would go over the step limit are caught by counting up their steps.
+    pub fn check(statements: &'a [Statement]) -> Result<(), ScriptError> {
+        Self {
+            checking: true,
+            ..Default::default()
+        }
+        .run(statements, &mut |_| Ok(()))
+    }
+
+    pub fn run<F>(&mut self, statements: &'a [Statement], step: &mut F) -> Result<(), ScriptError>
+    where
+        F: FnMut(Step) -> Result<(), ScriptErrorKind>,
+    {
+        for statement in statements {
+            let index = self.index;
+            self.index += 1;
+
+            let error = |kind| ScriptError {
+                index,
+                line: statement.line,
+                kind,
+            };
+
+            self.take_steps(1).map_err(error)?;
+
+            match &statement.command {
+                Command::Move(movement) => step(Step::Move(*movement)).map_err(error)?,
+                Command::Reset => step(Step::Reset).map_err(error)?,
+                Command::Repeat(count, body) if self.checking => {
+                    if *count > 0 {
+                        let start = self.steps;
+                        self.take_steps(1).map_err(error)?;
+                        self.run(body, step)?;
+                        let steps = self.steps - start;
+                        self.take_steps(steps.saturating_mul(count - 1))
+                            .map_err(error)?;
+                    }
+                }
+                Command::Repeat(count, body) => {
+                    for _ in 0..*count {
+                        self.take_steps(1).map_err(error)?;
+                        self.run(body, step)?;
+                    }
+                }
+                Command::Macro(name, body) => {
+                    self.macros.insert(name, body);
+                }
+                Command::Call(name) => {
+                    if self.calls.contains(&name.as_str()) {
+                        return Err(error(ScriptErrorKind::RecursiveMacro(name.clone())));
+                    }
+
+                    let body = *self
+                        .macros
+                        .get(name.as_str())
+                        .ok_or_else(|| error(ScriptErrorKind::UndefinedMacro(name.clone())))?;
+
+                    self.calls.push(name);
+                    self.run(body, step)?;
+                    self.calls.pop();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn take_steps(&mut self, steps: usize) -> Result<(), ScriptErrorKind> {
+        self.steps = self.steps.saturating_add(steps);
+
+        if self.steps > self.limit {
+            return Err(ScriptErrorKind::TooManySteps(self.limit));
+        }
+
+        Ok(())
+    }
+}
+
+fn write_command(script: &mut String, command: &Command, depth: usize) {
+    let indent = "  ".repeat(depth);
+    let mut write_body = |header: String, body: &[Statement]| {
+        script.push_str(&format!("{}{} {{\n", indent, header));
+        for statement in body {
+            write_command(script, &statement.command, depth + 1);
+        }
+        script.push_str(&format!("{}}}\n", indent));
+    };
+
+    match command {
+        Command::Move(movement) => script.push_str(&format!("{}{}\n", indent, movement)),
+        Command::Repeat(count, body) => write_body(format!("repeat {}", count), body),
+        Command::Macro(name, body) => write_body(format!("macro {}", name), body),
+        Command::Call(name) => script.push_str(&format!("{}call {}\n", indent, name)),
+        Command::Reset => script.push_str(&format!("{}reset\n", indent)),
+    }
+}
+
+/// Writes commands out as a script that parses back into the same commands, one to a line, with
+/// the bodies of blocks indented.
+pub fn format_script(commands: &[Command]) -> String {
+    let mut script = String::new();
+    for command in commands {
+        write_command(&mut script, command, 0);
+    }
+    script
+}
+
+// The lines of a script start after each newline, which is how the grammar finds the line of each
+// statement from its position.
+pub fn line_starts(s: &str) -> Vec<usize> {
+    let newlines = s.match_indices('\n').map(|(i, _)| i + 1);
+    [0].into_iter().chain(newlines).collect()
+}
+
+peg::parser! {
+    pub grammar command_parser(line_starts: &[usize]) for str {
+        pub rule parse() -> Vec<Statement>
+            = statements:block() ![_] {
+                statements
+            }
+
+        // Any number of statements, one to a line, with blank lines and comments between them.
+        rule block() -> Vec<Statement>
+            = statements:(gap() space() s:statement() end() { s })* gap() {
+                statements
+            }
+
+        rule statement() -> Statement
+            = p:position!() command:parse_command() {
+                let line = line_starts.partition_point(|&start| start <= p);
+                Statement { line, command }
+            }
+
+        rule parse_command() -> Command
+            = parse_movement() / parse_repeat() / parse_macro() / parse_call() / parse_reset()
+
+        rule parse_movement() -> Command
+            = direction:parse_direction() " " magnitude:parse_uint() {
+                Command::Move(Movement{ direction, magnitude })
+            }
+
+        rule parse_repeat() -> Command
+            = "repeat" " "+ count:parse_count() body:parse_body() {
+                Command::Repeat(count, body)
+            }
+
+        rule parse_macro() -> Command
+            = "macro" " "+ name:parse_name() body:parse_body() {
+                Command::Macro(name.to_string(), body)
+            }
+
+        rule parse_call() -> Command
+            = "call" " "+ name:parse_name() {
+                Command::Call(name.to_string())
+            }
+
+        rule parse_reset() -> Command
+            = "reset" {
+                Command::Reset
+            }
+
+        rule parse_body() -> Vec<Statement>
+            = space() "{" end() body:block() space() "}" {
+                body
+            }
+
+        rule parse_name() -> &'input str
+            = $(['a'..='z' | 'A'..='Z' | '_'] ['a'..='z' | 'A'..='Z' | '0'..='9' | '_']*)
+
+        rule parse_direction() -> Direction
+            = parse_forward() / parse_up() / parse_down() / parse_left() / parse_right()
+
+        rule parse_forward() -> Direction
+            = "forward" { Direction::Forward }
+
+        rule parse_down() -> Direction
+            = "down" { Direction::Down }
+
+        rule parse_up() -> Direction
+            = "up" { Direction::Up }
+
+        rule parse_left() -> Direction
+            = "left" { Direction::Left }
+
+        rule parse_right() -> Direction
+            = "right" { Direction::Right }
+
+        rule parse_uint() -> usize
+            = s:$(['0'..='9']+) {?
+                s.parse().or(Err("magnitude"))
+            }
+
+        rule parse_count() -> usize
+            = s:$(['0'..='9']+) {?
+                s.parse().or(Err("count"))
+            }
+
+        rule space() = [' ' | '\t']*
+
+        rule comment() = "#" [^'\n']*
+
+        // The rest of a line, which can only be a comment.
+        rule end() = space() comment()? ("\n" / ![_])
+
+        rule gap() = (space() comment()? "\n")*
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Vec<Statement> {
+        command_parser::parse(s, &line_starts(s)).unwrap()
+    }
+
+    fn steps(statements: &[Statement]) -> Result<Vec<Step>, ScriptError> {
+        let mut steps: Vec<Step> = Default::default();
+        Interpreter::default().run(statements, &mut |step| {
+            steps.push(step);
+            Ok(())
+        })?;
+        Ok(steps)
+    }
+
+    fn forward(magnitude: usize) -> Step {
+        Step::Move(Movement {
+            direction: Direction::Forward,
+            magnitude,
+        })
+    }
+
+    #[test]
+    fn test_parse() {
+        let statements = parse(
+            "# a comment\n\
+             forward 1  # another\n\
+             \n\
+             repeat 2 {\n\
+             \tcall dive\n\
+             }\n\
+             reset",
+        );
+
+        assert_eq!(statements.len(), 3);
+        assert_eq!(statements[0].line, 2);
+        assert_eq!(statements[1].line, 4);
+        assert_eq!(
+            statements[1].command,
+            Command::Repeat(
+                2,
+                vec![Statement {
+                    line: 5,
+                    command: Command::Call("dive".to_string())
+                }]
+            )
+        );
+        assert_eq!(statements[2].command, Command::Reset);
+    }
+
+    #[test]
+    fn test_run() {
+        let statements = parse(
+            "macro twice {\n\
+               forward 1\n\
+               forward 2\n\
+             }\n\
+             repeat 2 {\n\
+               call twice\n\
+             }\n\
+             repeat 0 {\n\
+               forward 9\n\
+             }\n\
+             reset\n",
+        );
+
+        assert_eq!(
+            steps(&statements).unwrap(),
+            vec![forward(1), forward(2), forward(1), forward(2), Step::Reset]
+        );
+        assert_eq!(Interpreter::check(&statements), Ok(()));
+    }
+
+    #[test]
+    fn test_format_script() {
+        let script = "\
+            forward 1\n\
+            macro dive {\n\
+            \x20 down 2\n\
+            \x20 repeat 3 {\n\
+            \x20   left 4\n\
+            \x20 }\n\
+            }\n\
+            call dive\n\
+            reset\n";
+        let commands: Vec<Command> = parse(script)
+            .into_iter()
+            .map(|statement| statement.command)
+            .collect();
+        assert_eq!(format_script(&commands), script);
+    }
+
+    #[test]
+    fn test_run_errors() {
+        let statements = parse("forward 1\ncall dive\nmacro dive {\n  down 1\n}\n");
+        let error = ScriptError {
+            index: 1,
+            line: 2,
+            kind: ScriptErrorKind::UndefinedMacro("dive".to_string()),
+        };
+        assert_eq!(steps(&statements), Err(error.clone()));
+        assert_eq!(Interpreter::check(&statements), Err(error));
+
+        let statements = parse("macro a {\n  call b\n}\nmacro b {\n  call a\n}\ncall a\n");
+        let error = ScriptError {
+            index: 4,
+            line: 5,
+            kind: ScriptErrorKind::RecursiveMacro("a".to_string()),
+        };
+        assert_eq!(steps(&statements), Err(error.clone()));
+        assert_eq!(Interpreter::check(&statements), Err(error.clone()));
+        assert_eq!(
+            error.to_string(),
+            "command 4 (line 5): macro `a` calls itself"
+        );
+    }
+
+    #[test]
+    fn test_step_limit() {
+        let statements = parse(
+            "forward 1
+repeat 99999999999 {
+  repeat 99999999999 {
+    forward 0
+  }
+}
+",
+        );
+        let error = Interpreter::check(&statements).unwrap_err();
+        assert_eq!(error.line, 3);
+        assert_eq!(error.kind, ScriptErrorKind::TooManySteps(STEP_LIMIT));
+
+        // Each statement and each time through a loop is a step, so looping over nothing still
+        // counts.
+        let statements = parse(
+            "repeat 3 {
+  forward 1
+}
+repeat 99999999999 {
+}
+",
+        );
+        let mut steps = 0;
+        let error = Interpreter::with_limit(10)
+            .run(&statements, &mut |_| {
+                steps += 1;
+                Ok(())
+            })
+            .unwrap_err();
+        assert_eq!((error.line, steps), (4, 3));
+        assert_eq!(
+            error.to_string(),
+            "command 4 (line 4): the script ran for more than 10 steps"
+        );
+        assert!(Interpreter::with_limit(7)
+            .run(&statements[..1], &mut |_| Ok(()))
+            .is_ok());
+    }
+}
@@ -0,0 +1,15 @@
+use day03::Day03;
+use solution::Solution;
+use std::fs;
+use std::process;
+
+fn main() {
+    let input = fs::read_to_string("input").unwrap();
+    match Day03::parse(&input) {
+        Ok(parsed) => println!("{}", Day03::solve_part_a(&parsed)),
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use num_bigint::BigUint;
+use std::fmt;
+use std::str::FromStr;
+
+const WORD_SIZE: usize = u64::BITS as usize;
+
+/// A binary number of any width, which keeps its leading zeros. Bits are numbered from the left,
+/// the way they're written, so bit 0 is the most significant.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BitVector {
+    width: usize,
+    words: Vec<u64>,
+}
+
+impl BitVector {
+    pub fn new(width: usize) -> Self {
+        Self {
+            width,
+            words: vec![0; width.div_ceil(WORD_SIZE)],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get(&self, i: usize) -> bool {
+        assert!(i < self.width, "bit {} is out of range", i);
+        self.words[i / WORD_SIZE] >> (i % WORD_SIZE) & 1 == 1
+    }
+
+    pub fn set(&mut self, i: usize, value: bool) {
+        assert!(i < self.width, "bit {} is out of range", i);
+        let mask = 1 << (i % WORD_SIZE);
+
+        if value {
+            self.words[i / WORD_SIZE] |= mask;
+        } else {
+            self.words[i / WORD_SIZE] &= !mask;
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.width).map(|i| self.get(i))
+    }
+
+    /// Flips every bit, keeping the same width.
+    pub fn complement(&self) -> Self {
+        let mut complement = Self::new(self.width);
+        for (i, bit) in self.iter().enumerate() {
+            complement.set(i, !bit);
+        }
+        complement
+    }
+
+    pub fn to_biguint(&self) -> BigUint {
+        let digits: Vec<u8> = self.iter().map(u8::from).collect();
+        BigUint::from_radix_be(&digits, 2).unwrap_or_default()
+    }
+}
+
+impl FromIterator<bool> for BitVector {
+    fn from_iter<I: IntoIterator<Item = bool>>(bits: I) -> Self {
+        let bits: Vec<bool> = bits.into_iter().collect();
+        let mut vector = Self::new(bits.len());
+        for (i, bit) in bits.into_iter().enumerate() {
+            vector.set(i, bit);
+        }
+        vector
+    }
+}
+
+impl FromStr for BitVector {
+    /// The byte offset of the first character that isn't a binary digit.
+    type Err = usize;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.char_indices()
+            .map(|(i, c)| match c {
+                '0' => Ok(false),
+                '1' => Ok(true),
+                _ => Err(i),
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for BitVector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for bit in self.iter() {
+            write!(f, "{}", if bit { '1' } else { '0' })?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bit_vector() {
+        let s = format!("{}101", "0".repeat(130));
+        let mut bits: BitVector = s.parse().unwrap();
+        assert_eq!(bits.width(), 133);
+        assert!(!bits.get(0));
+        assert!(bits.get(130) && !bits.get(131) && bits.get(132));
+        assert_eq!(bits.to_string(), s);
+        assert_eq!(bits.to_biguint(), BigUint::from(5u8));
+
+        bits.set(0, true);
+        assert_eq!(bits.to_biguint(), (BigUint::from(1u8) << 132u32) + 5u8);
+        assert_eq!(
+            bits.complement().to_biguint(),
+            (BigUint::from(1u8) << 132u32) - 6u8
+        );
+
+        assert_eq!("0120".parse::<BitVector>(), Err(2));
+        assert_eq!(BitVector::new(0).to_biguint(), BigUint::default());
+    }
+}
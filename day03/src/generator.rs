@@ -0,0 +1,35 @@
+use crate::Day03;
+use rand::Rng;
+use solution::{Generated, Generator};
+use std::collections::HashSet;
+
+const WIDTH: usize = 12;
+
+/// Distinct binary numbers as wide as the real input's, where the size is how many of them there
+/// are. Each column leans towards ones or zeros by a different amount, so the most common bits
+/// aren't just a coin toss.
+impl Generator for Day03 {
+    const DEFAULT_SIZE: usize = 1000;
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+        let size = size.clamp(1, 1 << WIDTH);
+        let odds: Vec<f64> = (0..WIDTH).map(|_| rng.gen_range(0.3..0.7)).collect();
+
+        let mut seen: HashSet<String> = Default::default();
+        let mut input = String::new();
+
+        while seen.len() < size {
+            let number: String = odds
+                .iter()
+                .map(|&odds| if rng.gen_bool(odds) { '1' } else { '0' })
+                .collect();
+
+            if seen.insert(number.clone()) {
+                input += &number;
+                input.push('\n');
+            }
+        }
+
+        Generated::new(input)
+    }
+}
@@ -0,0 +1,259 @@
+mod bits;
+mod generator;
+mod report;
+mod trie;
+
+pub use crate::bits::BitVector;
+pub use crate::report::{Column, Report};
+use crate::trie::BitTrie;
+pub use crate::trie::{FilterStep, Trace};
+use num_bigint::BigUint;
+use solution::{ParseError, Solution};
+
+// Every number in a report is the same width, which is how wide its lines are.
+fn get_width(numbers: &[BitVector]) -> usize {
+    numbers.first().map_or(0, BitVector::width)
+}
+
+fn get_columns(numbers: &[BitVector]) -> Vec<Column> {
+    let mut columns = vec![Column::default(); get_width(numbers)];
+
+    for number in numbers {
+        for (column, bit) in columns.iter_mut().zip(number.iter()) {
+            if bit {
+                column.ones += 1;
+            } else {
+                column.zeros += 1;
+            }
+        }
+    }
+
+    columns
+}
+
+fn get_gamma_rate(columns: &[Column]) -> BitVector {
+    columns
+        .iter()
+        .map(|column| column.ones >= column.zeros)
+        .collect()
+}
+
+fn get_power_consumption(numbers: &[BitVector]) -> BigUint {
+    let gamma = get_gamma_rate(&get_columns(numbers));
+    let epsilon = gamma.complement();
+
+    gamma.to_biguint() * epsilon.to_biguint()
+}
+
+fn build_trie(numbers: &[BitVector]) -> BitTrie {
+    let mut trie = BitTrie::new(get_width(numbers));
+    trie.extend(numbers);
+    trie
+}
+
+fn keep_most_common(ones: usize, zeros: usize) -> bool {
+    ones >= zeros
+}
+
+fn keep_least_common(ones: usize, zeros: usize) -> bool {
+    ones < zeros
+}
+
+fn get_rating<F>(numbers: &[BitVector], keep_ones: F) -> BigUint
+where
+    F: Fn(usize, usize) -> bool,
+{
+    build_trie(numbers)
+        .select(keep_ones)
+        .map(|rating| rating.to_biguint())
+        .unwrap_or_default()
+}
+
+fn get_oxygen_generator_rating(numbers: &[BitVector]) -> BigUint {
+    get_rating(numbers, keep_most_common)
+}
+
+fn get_co2_scrubber_rating(numbers: &[BitVector]) -> BigUint {
+    get_rating(numbers, keep_least_common)
+}
+
+fn get_life_support_rating(numbers: &[BitVector]) -> BigUint {
+    get_oxygen_generator_rating(numbers) * get_co2_scrubber_rating(numbers)
+}
+
+/// Gathers the counts behind the gamma and epsilon rates, and the trail that each of the ratings
+/// left while narrowing down the numbers.
+pub fn report(numbers: &[BitVector]) -> Report<'_> {
+    let columns = get_columns(numbers);
+    let gamma = get_gamma_rate(&columns);
+    let epsilon = gamma.complement();
+    let trie = build_trie(numbers);
+
+    Report {
+        numbers,
+        columns,
+        gamma,
+        epsilon,
+        oxygen_generator: trie.trace(keep_most_common),
+        co2_scrubber: trie.trace(keep_least_common),
+    }
+}
+
+fn parse_numbers(s: &str) -> Result<Vec<BitVector>, ParseError> {
+    let mut numbers: Vec<BitVector> = Default::default();
+
+    for line in s.lines() {
+        let number: BitVector = line
+            .parse()
+            .map_err(|i| ParseError::at(s, &line[i..], ["binary digit"]))?;
+
+        let width = get_width(&numbers);
+        if number.width() == 0 || (!numbers.is_empty() && number.width() != width) {
+            let expected = format!("binary number of {} digits", width.max(1));
+            return Err(ParseError::at(s, line, [expected]));
+        }
+
+        numbers.push(number);
+    }
+
+    Ok(numbers)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Vec<BitVector>;
+    type AnswerA = BigUint;
+    type AnswerB = BigUint;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_numbers(input)
+    }
+
+    fn solve_part_a(numbers: &Self::Input<'_>) -> Self::AnswerA {
+        get_power_consumption(numbers)
+    }
+
+    fn solve_part_b(numbers: &Self::Input<'_>) -> Self::AnswerB {
+        get_life_support_rating(numbers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_example() {
+        let input = fs::read_to_string("input-test").unwrap();
+        let numbers = Day03::parse(&input).unwrap();
+        assert_eq!(Day03::solve_part_a(&numbers), BigUint::from(198u8));
+        assert_eq!(Day03::solve_part_b(&numbers), BigUint::from(230u8));
+    }
+
+    // Works out the rating by filtering the numbers over and over, like the puzzle describes it.
+    fn filter_rating(numbers: &[u32], keep_ones: fn(usize, usize) -> bool) -> u32 {
+        let mut numbers = numbers.to_vec();
+
+        for bit in (0..12).rev() {
+            if numbers.len() == 1 {
+                break;
+            }
+
+            // When every number has the same bit, there's nothing to filter.
+            let ones = numbers.iter().filter(|&&n| n >> bit & 1 == 1).count();
+            let zeros = numbers.len() - ones;
+            if ones > 0 && zeros > 0 {
+                let keep = keep_ones(ones, zeros) as u32;
+                numbers.retain(|&n| n >> bit & 1 == keep);
+            }
+        }
+
+        numbers[0]
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let generated = solution::generate::<Day03>(seed, 200);
+            let numbers: Vec<u32> = generated
+                .input
+                .lines()
+                .map(|line| u32::from_str_radix(line, 2).unwrap())
+                .collect();
+            let parsed = Day03::parse(&generated.input).unwrap();
+
+            let gamma = (0..12)
+                .filter(|bit| numbers.iter().filter(|&&n| n >> bit & 1 == 1).count() >= 100)
+                .fold(0u32, |gamma, bit| gamma | 1 << bit);
+            assert_eq!(
+                Day03::solve_part_a(&parsed),
+                BigUint::from(gamma * (!gamma & 0xfff))
+            );
+
+            let oxygen = filter_rating(&numbers, keep_most_common);
+            let co2 = filter_rating(&numbers, keep_least_common);
+            assert_eq!(Day03::solve_part_b(&parsed), BigUint::from(oxygen * co2));
+        }
+    }
+
+    #[test]
+    fn test_wide_report() {
+        // The example, with 70 more digits on the left that are all ones, so the answers only
+        // differ by those digits.
+        let input = fs::read_to_string("input-test").unwrap();
+        let wide: String = input
+            .lines()
+            .map(|line| format!("{}{}\n", "1".repeat(70), line))
+            .collect();
+        let numbers = Day03::parse(&wide).unwrap();
+        assert_eq!(get_width(&numbers), 75);
+
+        let prefix = (BigUint::from(1u8) << 75u32) - (BigUint::from(1u8) << 5u32);
+        assert_eq!(get_oxygen_generator_rating(&numbers), &prefix + 23u8);
+        assert_eq!(get_co2_scrubber_rating(&numbers), &prefix + 10u8);
+        // Epsilon is all zeros on the left, since gamma is all ones there.
+        assert_eq!(Day03::solve_part_a(&numbers), (&prefix + 22u8) * 9u8);
+    }
+
+    #[test]
+    fn test_leading_zeros() {
+        // Nothing starts with a one, but the first bit still counts towards the width.
+        let numbers = Day03::parse("001\n010\n011\n").unwrap();
+        assert_eq!(get_width(&numbers), 3);
+        assert_eq!(Day03::solve_part_a(&numbers), BigUint::from(3u8 * 4));
+        assert_eq!(Day03::solve_part_b(&numbers), BigUint::from(3u8));
+    }
+
+    #[test]
+    fn test_report() {
+        let input = fs::read_to_string("input-test").unwrap();
+        let numbers = Day03::parse(&input).unwrap();
+        let report = report(&numbers);
+
+        let ties: Vec<bool> = report.columns.iter().map(Column::is_tie).collect();
+        assert_eq!(ties, [false; 5]);
+        assert_eq!(report.columns[0], Column { ones: 7, zeros: 5 });
+        assert_eq!(report.gamma.to_string(), "10110");
+
+        // The example goes through a tie on its way to the oxygen generator rating.
+        let oxygen = report.oxygen_generator.unwrap();
+        assert_eq!(oxygen.rating.to_string(), "10111");
+        assert_eq!((oxygen.steps[4].ones, oxygen.steps[4].zeros), (1, 1));
+        assert_eq!(oxygen.steps[4].eliminated.len(), 1);
+
+        let eliminated: usize = oxygen.steps.iter().map(|step| step.eliminated.len()).sum();
+        assert_eq!(eliminated, numbers.len() - 1);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day03::parse("0101\n0121\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+
+        let error = Day03::parse("0101\n010\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, vec!["binary number of 4 digits"]);
+    }
+}
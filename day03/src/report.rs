@@ -0,0 +1,97 @@
+use crate::bits::BitVector;
+use crate::trie::Trace;
+use std::fmt;
+
+/// How many of the numbers have each bit in one position.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Column {
+    pub ones: usize,
+    pub zeros: usize,
+}
+
+impl Column {
+    /// Whether neither bit is the most common, which the puzzle settles in favor of ones.
+    pub fn is_tie(&self) -> bool {
+        self.ones == self.zeros
+    }
+}
+
+/// Everything that goes into the answers, for seeing why they came out the way they did.
+#[derive(Debug, Clone)]
+pub struct Report<'a> {
+    pub numbers: &'a [BitVector],
+    pub columns: Vec<Column>,
+    pub gamma: BitVector,
+    pub epsilon: BitVector,
+    pub oxygen_generator: Option<Trace>,
+    pub co2_scrubber: Option<Trace>,
+}
+
+impl Report<'_> {
+    fn fmt_trace(&self, f: &mut fmt::Formatter, name: &str, trace: &Option<Trace>) -> fmt::Result {
+        let trace = match trace {
+            Some(trace) => trace,
+            None => return writeln!(f, "{} rating: none", name),
+        };
+
+        writeln!(
+            f,
+            "{} rating: {} ({})",
+            name,
+            trace.rating,
+            trace.rating.to_biguint()
+        )?;
+
+        for step in trace
+            .steps
+            .iter()
+            .filter(|step| !step.eliminated.is_empty())
+        {
+            writeln!(
+                f,
+                "  bit {}: {} ones, {} zeros, kept {}s, eliminated {}:",
+                step.bit,
+                step.ones,
+                step.zeros,
+                step.kept as u8,
+                step.eliminated.len()
+            )?;
+
+            for &index in step.eliminated.iter() {
+                writeln!(f, "    line {}: {}", index + 1, self.numbers[index])?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{:>5} {:>7} {:>7}", "bit", "ones", "zeros")?;
+
+        for (i, column) in self.columns.iter().enumerate() {
+            write!(f, "{:>5} {:>7} {:>7}", i, column.ones, column.zeros)?;
+            if column.is_tie() {
+                write!(f, "  tie")?;
+            }
+            writeln!(f)?;
+        }
+
+        writeln!(
+            f,
+            "gamma rate: {} ({})",
+            self.gamma,
+            self.gamma.to_biguint()
+        )?;
+        writeln!(
+            f,
+            "epsilon rate: {} ({})",
+            self.epsilon,
+            self.epsilon.to_biguint()
+        )?;
+
+        self.fmt_trace(f, "oxygen generator", &self.oxygen_generator)?;
+        self.fmt_trace(f, "CO2 scrubber", &self.co2_scrubber)
+    }
+}
@@ -0,0 +1,220 @@
+use crate::bits::BitVector;
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    // The nodes for the next bit being a zero or a one, if any number has it.
+    children: [Option<usize>; 2],
+    // How many numbers start with the bits that lead here.
+    count: usize,
+    // The order the numbers that end here were inserted in, which is only ever filled in for the
+    // nodes of the last bit.
+    indexes: Vec<usize>,
+}
+
+/// One bit of narrowing down the numbers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterStep {
+    pub bit: usize,
+    pub ones: usize,
+    pub zeros: usize,
+    pub kept: bool,
+    /// The numbers that didn't have the kept bit, by the order they were inserted in.
+    pub eliminated: Vec<usize>,
+}
+
+/// How a number was chosen, along with what was eliminated along the way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+    pub rating: BitVector,
+    pub steps: Vec<FilterStep>,
+}
+
+/// Numbers of the same width, stored by their bits from the most significant down, so that each
+/// node knows how many of the numbers share the bits that lead to it.
+#[derive(Debug, Clone)]
+pub struct BitTrie {
+    width: usize,
+    nodes: Vec<Node>,
+}
+
+impl BitTrie {
+    pub fn new(width: usize) -> Self {
+        Self {
+            width,
+            nodes: vec![Default::default()],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes[0].count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn insert(&mut self, bits: &BitVector) {
+        assert_eq!(
+            bits.width(),
+            self.width,
+            "every number must be the same width"
+        );
+
+        let index = self.len();
+        let mut node = 0;
+        self.nodes[node].count += 1;
+
+        for bit in bits.iter() {
+            node = match self.nodes[node].children[bit as usize] {
+                Some(child) => child,
+                None => {
+                    let child = self.nodes.len();
+                    self.nodes.push(Default::default());
+                    self.nodes[node].children[bit as usize] = Some(child);
+                    child
+                }
+            };
+            self.nodes[node].count += 1;
+        }
+
+        self.nodes[node].indexes.push(index);
+    }
+
+    // The indexes of every number that starts with the bits leading to the node.
+    fn members(&self, node: usize) -> Vec<usize> {
+        let mut members: Vec<usize> = Default::default();
+        let mut stack = vec![node];
+
+        while let Some(node) = stack.pop() {
+            members.extend(&self.nodes[node].indexes);
+            stack.extend(self.nodes[node].children.iter().rev().flatten());
+        }
+
+        members.sort_unstable();
+        members
+    }
+
+    /// Narrows the numbers down one bit at a time, keeping the ones with a one in that position if
+    /// `keep_ones` says to, given how many have a one and how many have a zero. A bit that all of
+    /// the remaining numbers share is kept no matter what, so it's only ever asked when there's a
+    /// choice to make. Each bit takes one step down the trie, so this never looks at the numbers
+    /// themselves.
+    pub fn select<F>(&self, keep_ones: F) -> Option<BitVector>
+    where
+        F: Fn(usize, usize) -> bool,
+    {
+        self.walk(keep_ones, |_, _| {})
+    }
+
+    /// Does the same as [`BitTrie::select`], while keeping track of which numbers each bit
+    /// eliminated. Every number is eliminated at most once, so this takes no longer than inserting
+    /// the numbers did.
+    pub fn trace<F>(&self, keep_ones: F) -> Option<Trace>
+    where
+        F: Fn(usize, usize) -> bool,
+    {
+        let mut steps: Vec<FilterStep> = Default::default();
+
+        let rating = self.walk(keep_ones, |mut step, dropped| {
+            if let Some(dropped) = dropped {
+                step.eliminated = self.members(dropped);
+            }
+            steps.push(step);
+        })?;
+
+        Some(Trace { rating, steps })
+    }
+
+    // Goes down the trie, handing each step to the callback along with the node that was left
+    // behind, if there was one.
+    fn walk<F, S>(&self, keep_ones: F, mut visit: S) -> Option<BitVector>
+    where
+        F: Fn(usize, usize) -> bool,
+        S: FnMut(FilterStep, Option<usize>),
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut node = 0;
+        let mut bits = BitVector::new(self.width);
+
+        for i in 0..self.width {
+            let count = |child: Option<usize>| child.map_or(0, |child| self.nodes[child].count);
+            let children = self.nodes[node].children;
+            let (zeros, ones) = (count(children[0]), count(children[1]));
+
+            let bit = match children {
+                [Some(_), Some(_)] => keep_ones(ones, zeros),
+                [Some(_), None] => false,
+                [None, Some(_)] => true,
+                [None, None] => unreachable!("every number is as wide as the trie"),
+            };
+
+            let step = FilterStep {
+                bit: i,
+                ones,
+                zeros,
+                kept: bit,
+                eliminated: Default::default(),
+            };
+            visit(step, children[!bit as usize]);
+
+            bits.set(i, bit);
+            node = children[bit as usize].unwrap();
+        }
+
+        Some(bits)
+    }
+}
+
+impl<'a> Extend<&'a BitVector> for BitTrie {
+    fn extend<I: IntoIterator<Item = &'a BitVector>>(&mut self, numbers: I) {
+        for bits in numbers {
+            self.insert(bits);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select() {
+        let mut trie = BitTrie::new(3);
+        trie.extend(&["110".parse().unwrap(), "111".parse().unwrap()]);
+        trie.insert(&"011".parse().unwrap());
+        assert_eq!(trie.len(), 3);
+
+        let select = |keep_ones: fn(usize, usize) -> bool| trie.select(keep_ones).unwrap();
+        assert_eq!(select(|ones, zeros| ones >= zeros).to_string(), "111");
+        assert_eq!(select(|ones, zeros| ones < zeros).to_string(), "011");
+        // Both of the numbers starting with a one also have a one next, so there's no choice.
+        assert_eq!(select(|_, _| true).to_string(), "111");
+
+        assert_eq!(BitTrie::new(3).select(|_, _| true), None);
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut trie = BitTrie::new(3);
+        for bits in ["110", "011", "111", "010", "110"] {
+            trie.insert(&bits.parse().unwrap());
+        }
+
+        let trace = trie.trace(|ones, zeros| ones >= zeros).unwrap();
+        assert_eq!(trace.rating.to_string(), "110");
+
+        let eliminated: Vec<(bool, &[usize])> = trace
+            .steps
+            .iter()
+            .map(|step| (step.kept, &step.eliminated[..]))
+            .collect();
+        assert_eq!(
+            eliminated,
+            vec![(true, &[1, 3][..]), (true, &[][..]), (false, &[2][..])]
+        );
+        assert_eq!((trace.steps[2].ones, trace.steps[2].zeros), (1, 2));
+    }
+}
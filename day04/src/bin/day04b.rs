@@ -0,0 +1,15 @@
+use day04::Day04;
+use solution::Solution;
+use std::fs;
+use std::process;
+
+fn main() {
+    let input = fs::read_to_string("input").unwrap();
+    match Day04::parse(&input) {
+        Ok(parsed) => println!("{}", Day04::solve_part_b(&parsed)),
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    }
+}
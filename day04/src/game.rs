@@ -0,0 +1,195 @@
+use crate::{Index, Number, Rule, Session};
+use std::fmt;
+
+/// A drawn number being found on a board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mark {
+    pub board: usize,
+    pub index: Index,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Win {
+    pub board: usize,
+    /// The turn the board won on, starting at 0 for the first number drawn.
+    pub turn: usize,
+    pub number: Number,
+    /// The numbers that completed the win, in the order they appear on the board.
+    pub pattern: Vec<Number>,
+    /// The sum of the board's unmarked numbers, times the number that was just drawn.
+    pub score: Number,
+}
+
+/// Everything that happened when one number was drawn.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Turn {
+    pub number: Number,
+    pub marks: Vec<Mark>,
+    /// The boards that won this turn, in the order of the boards.
+    pub wins: Vec<Win>,
+}
+
+impl fmt::Display for Turn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "drew {}, marking {} boards",
+            self.number,
+            self.marks.len()
+        )?;
+
+        for win in self.wins.iter() {
+            let pattern: Vec<String> = win.pattern.iter().map(|n| n.to_string()).collect();
+            write!(
+                f,
+                "\n  board {} wins with {}, scoring {}",
+                win.board,
+                pattern.join(" "),
+                win.score
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A whole game, turn by turn.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replay {
+    pub turns: Vec<Turn>,
+    /// The boards that were still in play after the last number was drawn.
+    pub losers: Vec<usize>,
+}
+
+impl Replay {
+    /// Every win, in the order they happened.
+    pub fn wins(&self) -> impl Iterator<Item = &Win> {
+        self.turns.iter().flat_map(|turn| turn.wins.iter())
+    }
+}
+
+impl Session {
+    /// Draws every number, marking it on each board that's still in play. A board leaves the game
+    /// as soon as it wins, with the first of its patterns to be completed, so it only ever wins
+    /// once and keeps the score it had then.
+    pub fn play(&self, rule: Rule) -> Replay {
+        self.play_moves(&self.moves, rule)
+    }
+
+    /// Plays the boards with a different draw order than the session's own.
+    pub fn play_moves(&self, moves: &[Number], rule: Rule) -> Replay {
+        let mut marked: Vec<Vec<bool>> = self
+            .boards
+            .iter()
+            .map(|board| vec![false; board.numbers.len()])
+            .collect();
+        let mut won = vec![false; self.boards.len()];
+        let mut turns: Vec<Turn> = Default::default();
+
+        for (turn, &number) in moves.iter().enumerate() {
+            let mut marks: Vec<Mark> = Default::default();
+            let mut wins: Vec<Win> = Default::default();
+
+            for (i, board) in self.boards.iter().enumerate() {
+                let index = match board.indexes.get(&number) {
+                    Some(&index) if !won[i] => index,
+                    _ => continue,
+                };
+
+                marked[i][index] = true;
+                marks.push(Mark { board: i, index });
+
+                let completed = board
+                    .patterns_at(index, rule)
+                    .into_iter()
+                    .find(|pattern| pattern.iter().all(|&index| marked[i][index]));
+
+                if let Some(pattern) = completed {
+                    won[i] = true;
+
+                    let unmarked_score: Number = board
+                        .numbers
+                        .iter()
+                        .zip(marked[i].iter())
+                        .filter(|(_, &marked)| !marked)
+                        .map(|(n, _)| n)
+                        .sum();
+
+                    wins.push(Win {
+                        board: i,
+                        turn,
+                        number,
+                        pattern: pattern.iter().map(|&index| board.numbers[index]).collect(),
+                        score: unmarked_score * number,
+                    });
+                }
+            }
+
+            turns.push(Turn {
+                number,
+                marks,
+                wins,
+            });
+        }
+
+        let losers = (0..self.boards.len()).filter(|&i| !won[i]).collect();
+
+        Replay { turns, losers }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Day04, Mark, Rule};
+    use solution::Solution;
+    use std::fs;
+
+    #[test]
+    fn test_play() {
+        let input = fs::read_to_string("input-test").unwrap();
+        let session = Day04::parse(&input).unwrap();
+        let replay = session.play(Default::default());
+
+        assert_eq!(replay.turns.len(), session.moves.len());
+        assert_eq!(replay.turns[0].marks.len(), 3);
+
+        let wins: Vec<(usize, usize, u32)> = replay
+            .wins()
+            .map(|win| (win.board, win.turn, win.score))
+            .collect();
+        assert_eq!(wins, vec![(2, 11, 4512), (0, 13, 2192), (1, 14, 1924)]);
+        assert_eq!(replay.wins().next().unwrap().pattern, [14, 21, 17, 24, 4]);
+        assert!(replay.losers.is_empty());
+
+        assert_eq!(replay.turns[1].to_string(), "drew 4, marking 3 boards");
+        assert_eq!(
+            replay.turns[11].to_string(),
+            "drew 24, marking 3 boards\n  board 2 wins with 14 21 17 24 4, scoring 4512"
+        );
+
+        // Every number gets drawn, so every board wins eventually.
+        let replay = session.play(Rule::FullCard);
+        assert_eq!(replay.wins().last().unwrap().turn, 26);
+        assert!(replay.losers.is_empty());
+    }
+
+    #[test]
+    fn test_play_losers() {
+        let session = Day04::parse("1,2,3\n\n1 2\n3 4\n\n5 6\n7 8\n\n1 4\n3 5\n").unwrap();
+        let replay = session.play(Default::default());
+
+        let wins: Vec<(usize, usize)> = replay.wins().map(|win| (win.board, win.turn)).collect();
+        assert_eq!(wins, [(0, 1), (2, 2)]);
+        assert_eq!(replay.losers, [1]);
+        // Board 0 is out of the game by the time 3 is drawn.
+        assert_eq!(replay.turns[2].marks, [Mark { board: 2, index: 2 }]);
+    }
+
+    #[test]
+    fn test_rule_from_str() {
+        for rule in Rule::ALL {
+            assert_eq!(rule.to_string().parse(), Ok(rule));
+        }
+        assert!("blackout".parse::<Rule>().is_err());
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{Board, Day04, Number, Session};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use solution::{Generated, Generator};
+
+// The real input draws every number below this, so every board wins eventually.
+const NUM_NUMBERS: usize = 100;
+
+// The boards are the same size as the real input's.
+const LINE_SIZE: usize = 5;
+
+/// Draws every number below `numbers` once, in a random order.
+pub fn random_draws<R: Rng + ?Sized>(rng: &mut R, numbers: usize) -> Vec<Number> {
+    let mut draws: Vec<Number> = (0..numbers as Number).collect();
+    draws.shuffle(rng);
+    draws
+}
+
+/// Fills a board of the given size with different numbers, chosen from the draws.
+///
+/// # Panics
+///
+/// Panics if there aren't enough draws to fill the board.
+pub fn random_board<R: Rng + ?Sized>(rng: &mut R, size: usize, draws: &[Number]) -> Board {
+    assert!(
+        draws.len() >= size * size,
+        "not enough numbers to fill a board"
+    );
+    Board::new(
+        size,
+        draws.choose_multiple(rng, size * size).copied().collect(),
+    )
+}
+
+/// A session with a random draw order and the given number of random boards, which all win
+/// eventually.
+pub fn random_session<R: Rng + ?Sized>(
+    rng: &mut R,
+    boards: usize,
+    size: usize,
+    numbers: usize,
+) -> Session {
+    let moves = random_draws(rng, numbers);
+    let boards = (0..boards)
+        .map(|_| random_board(rng, size, &moves))
+        .collect();
+
+    Session { moves, boards }
+}
+
+/// A bingo session where the size is the number of boards.
+impl Generator for Day04 {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+        Generated::new(random_session(rng, size, LINE_SIZE, NUM_NUMBERS).to_string())
+    }
+}
@@ -0,0 +1,381 @@
+mod game;
+mod generator;
+mod simulation;
+
+pub use crate::game::{Mark, Replay, Turn, Win};
+pub use crate::generator::{random_board, random_draws, random_session};
+pub use crate::simulation::{Estimate, Odds, Simulation};
+use solution::{ParseError, Solution};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+type Index = usize;
+type Number = u32;
+
+fn get_row(index: Index, size: usize) -> Index {
+    index / size
+}
+
+fn get_column(index: Index, size: usize) -> Index {
+    index % size
+}
+
+fn get_row_line(index: Index, size: usize) -> Vec<Index> {
+    let row = get_row(index, size) * size;
+    (row..row + size).collect()
+}
+
+fn get_column_line(index: Index, size: usize) -> Vec<Index> {
+    let column = get_column(index, size);
+    (column..size * size).step_by(size).collect()
+}
+
+// The diagonals that go through the index, from the top left down and from the top right down.
+fn get_diagonal_lines(index: Index, size: usize) -> Vec<Vec<Index>> {
+    let (row, column) = (get_row(index, size), get_column(index, size));
+    let mut lines: Vec<Vec<Index>> = Default::default();
+
+    if row == column {
+        lines.push((0..size).map(|i| i * size + i).collect());
+    }
+    if row + column == size - 1 {
+        lines.push((0..size).map(|i| i * size + size - 1 - i).collect());
+    }
+
+    lines
+}
+
+fn get_corners(size: usize) -> Vec<Index> {
+    vec![0, size - 1, size * (size - 1), size * size - 1]
+}
+
+/// What it takes for a board to win.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    /// Every number in a row or column is marked, or in either diagonal if they count.
+    Line { diagonals: bool },
+    /// Every number on the board is marked.
+    FullCard,
+    /// The number in each corner is marked.
+    FourCorners,
+}
+
+impl Rule {
+    pub const ALL: [Rule; 4] = [
+        Rule::Line { diagonals: false },
+        Rule::Line { diagonals: true },
+        Rule::FullCard,
+        Rule::FourCorners,
+    ];
+}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Rule::Line { diagonals: false }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rule::Line { diagonals: false } => write!(f, "lines"),
+            Rule::Line { diagonals: true } => write!(f, "diagonals"),
+            Rule::FullCard => write!(f, "full-card"),
+            Rule::FourCorners => write!(f, "four-corners"),
+        }
+    }
+}
+
+impl FromStr for Rule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|rule| rule.to_string() == s)
+            .ok_or_else(|| format!("unknown rule: {}", s))
+    }
+}
+
+/// A square board of numbers, which can be any size.
+#[derive(Debug, Default, PartialEq)]
+pub struct Board {
+    size: usize,
+    numbers: Vec<Number>,
+    indexes: HashMap<Number, Index>,
+}
+
+impl Board {
+    fn new(size: usize, numbers: Vec<Number>) -> Self {
+        assert_eq!(numbers.len(), size * size, "a board must be square");
+
+        let mut indexes: HashMap<Number, Index> = Default::default();
+        for (index, &number) in numbers.iter().enumerate() {
+            indexes.insert(number, index);
+        }
+        Self {
+            size,
+            numbers,
+            indexes,
+        }
+    }
+
+    // A board is as wide as it has rows, and every row needs to be that wide.
+    fn from_rows(rows: Vec<Vec<Number>>) -> Option<Self> {
+        let size = rows.len();
+
+        if rows.iter().any(|row| row.len() != size) {
+            return None;
+        }
+
+        Some(Self::new(size, rows.into_iter().flatten().collect()))
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    // The sets of spaces that would make the board win under the rule, which marking the space at
+    // the index could have completed.
+    fn patterns_at(&self, index: Index, rule: Rule) -> Vec<Vec<Index>> {
+        let mut patterns: Vec<Vec<Index>> = Default::default();
+
+        match rule {
+            Rule::Line { diagonals } => {
+                patterns.push(get_row_line(index, self.size));
+                patterns.push(get_column_line(index, self.size));
+                if diagonals {
+                    patterns.extend(get_diagonal_lines(index, self.size));
+                }
+            }
+            Rule::FullCard => patterns.push((0..self.numbers.len()).collect()),
+            Rule::FourCorners => {
+                let corners = get_corners(self.size);
+                if corners.contains(&index) {
+                    patterns.push(corners);
+                }
+            }
+        }
+
+        patterns
+    }
+}
+
+#[derive(Debug)]
+pub struct Session {
+    moves: Vec<Number>,
+    boards: Vec<Board>,
+}
+
+// Writes the session the way it's read, with every number on the boards lined up.
+impl fmt::Display for Session {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let moves: Vec<String> = self.moves.iter().map(|n| n.to_string()).collect();
+        writeln!(f, "{}", moves.join(","))?;
+
+        let width = self
+            .boards
+            .iter()
+            .flat_map(|board| board.numbers.iter())
+            .map(|n| n.to_string().len())
+            .max()
+            .unwrap_or(0)
+            .max(2);
+
+        for board in self.boards.iter() {
+            writeln!(f)?;
+            for row in board.numbers.chunks(board.size) {
+                let row: Vec<String> = row.iter().map(|n| format!("{:>width$}", n)).collect();
+                writeln!(f, "{}", row.join(" "))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Session {
+    pub fn get_first_win_score(&self, rule: Rule) -> Option<Number> {
+        self.play(rule).wins().next().map(|win| win.score)
+    }
+
+    pub fn get_last_win_score(&self, rule: Rule) -> Option<Number> {
+        self.play(rule).wins().last().map(|win| win.score)
+    }
+}
+
+peg::parser! {
+    grammar session_parser() for str {
+        pub rule parse() -> Session
+            = moves:parse_moves() "\n\n" boards:(parse_board() ++ "\n\n") "\n" {
+                Session{ moves, boards }
+            }
+
+        rule parse_moves() -> Vec<Number>
+            = numbers:(parse_uint() ++ ",") {
+                numbers
+            }
+
+        rule parse_board() -> Board
+            = rows:(parse_row() ++ "\n") {?
+                Board::from_rows(rows).ok_or("square board")
+            }
+
+        rule parse_row() -> Vec<Number>
+            = parse_space() ++ " "
+
+        rule parse_space() -> Number
+            = " "* number:parse_uint() {
+                number
+            }
+
+        rule parse_uint() -> Number
+            = s:$(['0'..='9']+) {?
+                s.parse().or(Err("number"))
+            }
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Session;
+    type AnswerA = Number;
+    type AnswerB = Number;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        session_parser::parse(input).map_err(|error| ParseError::from_peg(input, error))
+    }
+
+    fn solve_part_a(session: &Self::Input<'_>) -> Self::AnswerA {
+        session.get_first_win_score(Default::default()).unwrap()
+    }
+
+    fn solve_part_b(session: &Self::Input<'_>) -> Self::AnswerB {
+        session.get_last_win_score(Default::default()).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    const SIZE: usize = 5;
+
+    #[test]
+    fn test_get_row() {
+        for row in 0..SIZE {
+            let start = row * SIZE;
+            let end = start + SIZE;
+            for index in start..end {
+                assert_eq!(get_row(index, SIZE), row);
+            }
+        }
+    }
+
+    #[test]
+    fn test_get_column() {
+        for column in 0..SIZE {
+            let start = column;
+            let end = 20 + start;
+            for index in (start..end).step_by(SIZE) {
+                assert_eq!(get_column(index, SIZE), column);
+            }
+        }
+    }
+
+    #[test]
+    fn test_get_row_line() {
+        for row in 0..SIZE {
+            let start = row * SIZE;
+            let line: Vec<Index> = (start..start + SIZE).collect();
+            for &index in line.iter() {
+                assert_eq!(get_row_line(index, SIZE), line);
+            }
+        }
+    }
+
+    #[test]
+    fn test_get_column_line() {
+        for column in 0..SIZE {
+            let start = column;
+            let line: Vec<Index> = (start..SIZE * SIZE).step_by(SIZE).collect();
+            for &index in line.iter() {
+                assert_eq!(get_column_line(index, SIZE), line);
+            }
+        }
+    }
+
+    #[test]
+    fn test_get_diagonal_lines() {
+        assert_eq!(get_diagonal_lines(4, 3), vec![vec![0, 4, 8], vec![2, 4, 6]]);
+        assert_eq!(get_diagonal_lines(6, 3), vec![vec![2, 4, 6]]);
+        assert!(get_diagonal_lines(1, 3).is_empty());
+    }
+
+    #[test]
+    fn test_board_patterns_at() {
+        let board = Board::new(SIZE, (0..(SIZE * SIZE) as Number).collect());
+        let index = 12;
+        assert_eq!(
+            board.patterns_at(index, Default::default()),
+            vec![get_row_line(index, SIZE), get_column_line(index, SIZE)]
+        );
+        assert_eq!(
+            board
+                .patterns_at(index, Rule::Line { diagonals: true })
+                .len(),
+            4
+        );
+        assert!(board.patterns_at(index, Rule::FourCorners).is_empty());
+        assert_eq!(
+            board.patterns_at(20, Rule::FourCorners),
+            vec![vec![0, 4, 20, 24]]
+        );
+        assert_eq!(board.patterns_at(0, Rule::FullCard)[0].len(), 25);
+    }
+
+    #[test]
+    fn test_example() {
+        let input = fs::read_to_string("input-test").unwrap();
+        let session = session_parser::parse(&input).unwrap();
+        assert_eq!(session.get_first_win_score(Default::default()), Some(4512));
+        assert_eq!(session.get_last_win_score(Default::default()), Some(1924));
+    }
+
+    #[test]
+    fn test_sizes_and_rules() {
+        let input = "1,5,9,3,7,2,4,6,8\n\n1 2 3\n4 5 6\n7 8 9\n\n9 8\n7 6\n";
+        let session = Day04::parse(input).unwrap();
+        let sizes: Vec<usize> = session.boards.iter().map(Board::size).collect();
+        assert_eq!(sizes, [3, 2]);
+
+        let first = |rule| session.get_first_win_score(rule);
+        // The 2x2 board wins on its first column once 7 is drawn.
+        assert_eq!(first(Default::default()), Some((8 + 6) * 7));
+        // The 3x3 board gets its diagonal with 9.
+        assert_eq!(
+            first(Rule::Line { diagonals: true }),
+            Some((2 + 3 + 4 + 6 + 7 + 8) * 9)
+        );
+        // Every number on the 2x2 board is a corner, so it needs all of them.
+        assert_eq!(first(Rule::FourCorners), Some((2 + 4 + 6 + 8) * 7));
+        assert_eq!(session.get_last_win_score(Rule::FourCorners), Some(0));
+        assert_eq!(first(Rule::FullCard), Some(0));
+
+        assert!(Day04::parse("1,2\n\n1 2\n3 4\n5 6\n").is_err());
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let generated = solution::generate::<Day04>(seed, 20);
+            let session = Day04::parse(&generated.input).unwrap();
+            assert_eq!(session.boards.len(), 20);
+            assert!(session.get_first_win_score(Default::default()).is_some());
+            assert!(session.get_last_win_score(Default::default()).is_some());
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{Rule, Session};
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use std::fmt;
+
+/// Plays a session's boards over and over with random draw orders, made from the numbers the
+/// session draws. The same seed always gives the same draw orders.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Simulation {
+    pub trials: usize,
+    pub seed: u64,
+    pub rule: Rule,
+}
+
+impl Default for Simulation {
+    fn default() -> Self {
+        Self {
+            trials: 1000,
+            seed: 0,
+            rule: Default::default(),
+        }
+    }
+}
+
+/// How one board fared across every trial. Boards that win on the same draw are ranked by their
+/// order in the session, like the puzzle does.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Odds {
+    pub first: f64,
+    /// How often the board was the last of the winning boards to win, out of every trial. Boards
+    /// that never win don't count, so this is 0 for each board in trials where none of them win.
+    pub last: f64,
+    pub never: f64,
+    /// The mean number of draws the board took to win, over the trials where it won.
+    pub expected_draws: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Estimate {
+    pub trials: usize,
+    /// The odds for each board, in the order of the session.
+    pub odds: Vec<Odds>,
+}
+
+impl fmt::Display for Estimate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>5} {:>7} {:>7} {:>7} {:>7}",
+            "board", "first", "last", "never", "draws"
+        )?;
+
+        for (board, odds) in self.odds.iter().enumerate() {
+            let draws = odds
+                .expected_draws
+                .map_or("-".to_string(), |draws| format!("{:.1}", draws));
+            writeln!(
+                f,
+                "{:>5} {:>6.1}% {:>6.1}% {:>6.1}% {:>7}",
+                board,
+                odds.first * 100.0,
+                odds.last * 100.0,
+                odds.never * 100.0,
+                draws
+            )?;
+        }
+
+        write!(f, "({} trials)", self.trials)
+    }
+}
+
+impl Simulation {
+    /// # Panics
+    ///
+    /// Panics if there are no trials to run.
+    pub fn run(&self, session: &Session) -> Estimate {
+        assert!(self.trials > 0, "there must be at least one trial");
+
+        let boards = session.boards.len();
+        let mut firsts = vec![0; boards];
+        let mut lasts = vec![0; boards];
+        let mut wins = vec![0; boards];
+        let mut draws = vec![0; boards];
+
+        let mut rng = ChaCha8Rng::seed_from_u64(self.seed);
+        let mut moves = session.moves.clone();
+
+        for _ in 0..self.trials {
+            moves.shuffle(&mut rng);
+            let replay = session.play_moves(&moves, self.rule);
+
+            if let Some(win) = replay.wins().next() {
+                firsts[win.board] += 1;
+            }
+            if let Some(win) = replay.wins().last() {
+                lasts[win.board] += 1;
+            }
+
+            for win in replay.wins() {
+                wins[win.board] += 1;
+                draws[win.board] += win.turn + 1;
+            }
+        }
+
+        let trials = self.trials as f64;
+        let odds = (0..boards)
+            .map(|i| Odds {
+                first: firsts[i] as f64 / trials,
+                last: lasts[i] as f64 / trials,
+                never: (self.trials - wins[i]) as f64 / trials,
+                expected_draws: (wins[i] > 0).then(|| draws[i] as f64 / wins[i] as f64),
+            })
+            .collect();
+
+        Estimate {
+            trials: self.trials,
+            odds,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day04;
+    use solution::Solution;
+
+    #[test]
+    fn test_run() {
+        // Each board only needs its one number drawn, so the first two are equally likely to win
+        // first or last, and the third never wins at all.
+        let session = Day04::parse("1,2,3,4\n\n1\n\n2\n\n9\n").unwrap();
+        let simulation = Simulation {
+            trials: 2000,
+            ..Default::default()
+        };
+        let estimate = simulation.run(&session);
+
+        for odds in estimate.odds[..2].iter() {
+            assert!((odds.first - 0.5).abs() < 0.05);
+            assert!((odds.last - 0.5).abs() < 0.05);
+            assert_eq!(odds.never, 0.0);
+            // A number's place in a random order of four is 2.5 on average.
+            assert!((odds.expected_draws.unwrap() - 2.5).abs() < 0.1);
+        }
+
+        assert_eq!(
+            estimate.odds[2],
+            Odds {
+                never: 1.0,
+                ..Default::default()
+            }
+        );
+
+        assert_eq!(simulation.run(&session), estimate);
+        assert_ne!(
+            Simulation {
+                seed: 1,
+                ..simulation
+            }
+            .run(&session),
+            estimate
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let estimate = Estimate {
+            trials: 10,
+            odds: vec![
+                Odds {
+                    first: 0.25,
+                    last: 0.5,
+                    never: 0.0,
+                    expected_draws: Some(12.34),
+                },
+                Odds {
+                    never: 1.0,
+                    ..Default::default()
+                },
+            ],
+        };
+        assert_eq!(
+            estimate.to_string(),
+            "board   first    last   never   draws\n\
+             \x20   0   25.0%   50.0%    0.0%    12.3\n\
+             \x20   1    0.0%    0.0%  100.0%       -\n\
+             (10 trials)"
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{Coord, Day05};
+use rand::Rng;
+use solution::{Generated, Generator};
+
+// The real input keeps every coordinate below this.
+const EXTENT: Coord = 1000;
+
+const DIRECTIONS: [(Coord, Coord); 8] = [
+    (1, 0),
+    (-1, 0),
+    (0, 1),
+    (0, -1),
+    (1, 1),
+    (1, -1),
+    (-1, 1),
+    (-1, -1),
+];
+
+// How far a line can go from `start` in the direction `step` without leaving the extent.
+fn max_length(start: Coord, step: Coord) -> Coord {
+    match step {
+        1 => EXTENT - 1 - start,
+        -1 => start,
+        _ => Coord::MAX,
+    }
+}
+
+/// Horizontal, vertical and diagonal vent lines, where the size is the number of lines.
+impl Generator for Day05 {
+    const DEFAULT_SIZE: usize = 500;
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+        let mut input = String::new();
+        let mut count = 0;
+
+        while count < size {
+            let (x1, y1) = (rng.gen_range(0..EXTENT), rng.gen_range(0..EXTENT));
+            let (dx, dy) = DIRECTIONS[rng.gen_range(0..DIRECTIONS.len())];
+
+            // Lines are never a single point, so pick another start if it's against the edge.
+            let max_length = max_length(x1, dx).min(max_length(y1, dy));
+            if max_length < 1 {
+                continue;
+            }
+
+            let length = rng.gen_range(1..=max_length);
+            let (x2, y2) = (x1 + dx * length, y1 + dy * length);
+            input += &format!("{},{} -> {},{}\n", x1, y1, x2, y2);
+            count += 1;
+        }
+
+        Generated::new(input)
+    }
+}
@@ -0,0 +1,351 @@
+use crate::{Coord, Vec2};
+use geometry::BoundingBox;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+// The largest value a plain graymap can hold.
+const PGM_MAX: usize = 65535;
+
+// The longest that a line of a plain graymap or pixmap should be.
+const NETPBM_LINE_LENGTH: usize = 70;
+
+/// The most cells a heatmap can have. That's plenty for the real input at full resolution, but it
+/// stops a few far flung points from needing more memory than there is.
+pub const MAX_CELLS: usize = 1 << 24;
+
+/// A heatmap that would have more than [`MAX_CELLS`] cells with the given bin size, so it needs a
+/// bigger one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooManyCells {
+    pub bin: Coord,
+}
+
+impl fmt::Display for TooManyCells {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "a heatmap with a bin size of {} would have more than {} cells",
+            self.bin, MAX_CELLS
+        )
+    }
+}
+
+impl Error for TooManyCells {}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// A plain graymap, where brighter cells have more hits.
+    #[default]
+    Pgm,
+    /// A plain pixmap, going from black through red and yellow to white as the hits go up.
+    Ppm,
+    /// The hits in each cell, with a line for each row of cells.
+    Csv,
+}
+
+impl Format {
+    pub const ALL: [Format; 3] = [Format::Pgm, Format::Ppm, Format::Csv];
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Format::Pgm => write!(f, "pgm"),
+            Format::Ppm => write!(f, "ppm"),
+            Format::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|format| format.to_string() == s)
+            .ok_or_else(|| format!("unknown format: {}", s))
+    }
+}
+
+/// The hits on each point, summed over square cells of points.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heatmap {
+    /// The point at the top left corner of the first cell.
+    pub origin: Vec2,
+    /// The number of points along each side of a cell.
+    pub bin: Coord,
+    pub width: usize,
+    pub height: usize,
+    /// The hits in each cell, row by row.
+    pub cells: Vec<usize>,
+}
+
+impl Heatmap {
+    /// Lines up the cells so that the origin is a multiple of the bin size, which must be at least
+    /// 1. The map covers the points that have any hits, and is empty if none do.
+    pub fn new(hits: &HashMap<Vec2, usize>, bin: Coord) -> Result<Self, TooManyCells> {
+        assert!(bin >= 1, "bin size must be at least 1");
+
+        let bounds = match BoundingBox::from_points(hits.keys().copied()) {
+            Some(bounds) => bounds,
+            None => {
+                return Ok(Self {
+                    origin: Default::default(),
+                    bin,
+                    width: 0,
+                    height: 0,
+                    cells: Default::default(),
+                })
+            }
+        };
+
+        let cell = |point: Vec2| Vec2::new(point.x().div_euclid(bin), point.y().div_euclid(bin));
+        let (first, last) = (cell(bounds.min), cell(bounds.max));
+
+        let count = |first: Coord, last: Coord| {
+            usize::try_from(last.checked_sub(first)?)
+                .ok()?
+                .checked_add(1)
+        };
+        let (width, height) = count(first.x(), last.x())
+            .zip(count(first.y(), last.y()))
+            .filter(|&(width, height)| {
+                width
+                    .checked_mul(height)
+                    .is_some_and(|cells| cells <= MAX_CELLS)
+            })
+            .ok_or(TooManyCells { bin })?;
+
+        let mut cells = vec![0; width * height];
+
+        for (&point, &count) in hits.iter() {
+            let offset = cell(point) - first;
+            cells[offset.y() as usize * width + offset.x() as usize] += count;
+        }
+
+        Ok(Self {
+            origin: first * bin,
+            bin,
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[usize]> {
+        // Chunks of zero would panic, and there are no rows anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The most hits in any one cell.
+    pub fn max(&self) -> usize {
+        self.cells.iter().copied().max().unwrap_or(0)
+    }
+
+    pub fn write(&self, format: Format, w: &mut impl Write) -> io::Result<()> {
+        match format {
+            Format::Pgm => self.write_pgm(w),
+            Format::Ppm => self.write_ppm(w),
+            Format::Csv => self.write_csv(w),
+        }
+    }
+
+    fn write_pgm(&self, w: &mut impl Write) -> io::Result<()> {
+        let max = self.max();
+        let max_value = max.clamp(1, PGM_MAX);
+        writeln!(w, "P2\n{} {}\n{}", self.width, self.height, max_value)?;
+
+        for row in self.rows() {
+            let values = row
+                .iter()
+                .map(|&hits| scale(hits, max, max_value).to_string());
+            write_netpbm_row(w, values, " ")?;
+        }
+
+        Ok(())
+    }
+
+    fn write_ppm(&self, w: &mut impl Write) -> io::Result<()> {
+        let max = self.max().max(1);
+        writeln!(w, "P3\n{} {}\n255", self.width, self.height)?;
+
+        for row in self.rows() {
+            let values = row.iter().map(|&hits| {
+                let [r, g, b] = get_color(hits, max);
+                format!("{} {} {}", r, g, b)
+            });
+            write_netpbm_row(w, values, "  ")?;
+        }
+
+        Ok(())
+    }
+
+    fn write_csv(&self, w: &mut impl Write) -> io::Result<()> {
+        for row in self.rows() {
+            let values: Vec<String> = row.iter().map(|hits| hits.to_string()).collect();
+            writeln!(w, "{}", values.join(","))?;
+        }
+
+        Ok(())
+    }
+}
+
+// Each row starts on a new line, and goes on to as many more as it needs to keep them short enough.
+fn write_netpbm_row(
+    w: &mut impl Write,
+    values: impl Iterator<Item = String>,
+    separator: &str,
+) -> io::Result<()> {
+    let mut length = 0;
+
+    for value in values {
+        if length > 0 && length + separator.len() + value.len() > NETPBM_LINE_LENGTH {
+            writeln!(w)?;
+            length = 0;
+        }
+
+        if length > 0 {
+            write!(w, "{}", separator)?;
+            length += separator.len();
+        }
+
+        write!(w, "{}", value)?;
+        length += value.len();
+    }
+
+    writeln!(w)
+}
+
+// Cells are scaled down to fit when there are more hits than a value can hold, keeping the busiest
+// cell at full brightness.
+fn scale(hits: usize, max: usize, max_value: usize) -> usize {
+    if max <= max_value {
+        hits
+    } else {
+        hits * max_value / max
+    }
+}
+
+// Fills red, then green, then blue, so the color gets brighter along the way.
+fn get_color(hits: usize, max: usize) -> [u8; 3] {
+    let level = hits * 3 * 255 / max;
+    [0, 1, 2].map(|channel| level.saturating_sub(channel * 255).min(255) as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{get_hits, Day05, Rasterization};
+    use solution::Solution;
+
+    fn get_heatmap(input: &str, bin: Coord) -> Heatmap {
+        let lines = Day05::parse(input).unwrap();
+        Heatmap::new(&get_hits(&lines, Rasterization::Lattice), bin).unwrap()
+    }
+
+    fn write(heatmap: &Heatmap, format: Format) -> String {
+        let mut output: Vec<u8> = Default::default();
+        heatmap.write(format, &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_heatmap() {
+        let heatmap = get_heatmap("1,1 -> 3,1\n2,0 -> 2,3\n", 1);
+        assert_eq!(heatmap.origin, Vec2::new(1, 0));
+        assert_eq!((heatmap.width, heatmap.height), (3, 4));
+        assert_eq!(heatmap.max(), 2);
+        assert_eq!(write(&heatmap, Format::Csv), "0,1,0\n1,2,1\n0,1,0\n0,1,0\n");
+        assert_eq!(
+            write(&heatmap, Format::Pgm),
+            "P2\n3 4\n2\n0 1 0\n1 2 1\n0 1 0\n0 1 0\n"
+        );
+        assert_eq!(
+            write(&heatmap, Format::Ppm).lines().nth(4),
+            Some("255 127 0  255 255 255  255 127 0")
+        );
+    }
+
+    #[test]
+    fn test_heatmap_bins() {
+        // The cells line up with multiples of the bin size, not with the first point.
+        let heatmap = get_heatmap("1,1 -> 3,1\n2,0 -> 2,3\n", 2);
+        assert_eq!(heatmap.origin, Vec2::new(0, 0));
+        assert_eq!((heatmap.width, heatmap.height), (2, 2));
+        assert_eq!(write(&heatmap, Format::Csv), "1,4\n0,2\n");
+        assert_eq!(heatmap.cells.iter().sum::<usize>(), 7);
+
+        let heatmap = Heatmap::new(&Default::default(), 10).unwrap();
+        assert_eq!((heatmap.width, heatmap.height), (0, 0));
+        assert_eq!(write(&heatmap, Format::Pgm), "P2\n0 0\n1\n");
+    }
+
+    #[test]
+    fn test_heatmap_scale() {
+        let hits = HashMap::from([(Vec2::new(0, 0), 100000), (Vec2::new(1, 0), 50000)]);
+        let heatmap = Heatmap::new(&hits, 1).unwrap();
+        assert_eq!(
+            write(&heatmap, Format::Pgm),
+            "P2\n2 1\n65535\n65535 32767\n"
+        );
+    }
+
+    #[test]
+    fn test_heatmap_full_size() {
+        // About as many lines as the real input, spread over as many points.
+        let generated = solution::generate::<Day05>(0, 500);
+        let heatmap = get_heatmap(&generated.input, 1);
+        assert!(heatmap.width * heatmap.height > 900000);
+
+        assert_eq!(write(&heatmap, Format::Csv).lines().count(), heatmap.height);
+        for format in [Format::Pgm, Format::Ppm] {
+            let output = write(&heatmap, format);
+            assert!(output.lines().all(|line| line.len() <= NETPBM_LINE_LENGTH));
+        }
+    }
+
+    #[test]
+    fn test_heatmap_too_many_cells() {
+        let input = "0,0 -> 0,0\n999999999,999999999 -> 999999999,999999999\n";
+        let lines = Day05::parse(input).unwrap();
+        let hits = get_hits(&lines, Rasterization::Lattice);
+        assert_eq!(Heatmap::new(&hits, 1), Err(TooManyCells { bin: 1 }));
+        assert_eq!(
+            Heatmap::new(&hits, 1000000).unwrap().cells.len(),
+            1000 * 1000
+        );
+
+        let hits = HashMap::from([(Vec2::new(Coord::MIN, 0), 1), (Vec2::new(Coord::MAX, 0), 1)]);
+        assert_eq!(Heatmap::new(&hits, 1), Err(TooManyCells { bin: 1 }));
+    }
+
+    #[test]
+    fn test_heatmap_wrapping() {
+        // Each row starts on its own line, but is broken up to keep the lines short.
+        let hits: HashMap<Vec2, usize> = (0..30)
+            .flat_map(|x| [(Vec2::new(x, 0), 10), (Vec2::new(x, 1), 1)])
+            .collect();
+        let output = write(&Heatmap::new(&hits, 1).unwrap(), Format::Pgm);
+        let lines: Vec<&str> = output.lines().skip(3).collect();
+        assert_eq!(
+            lines,
+            vec![
+                ["10"; 23].join(" ").as_str(),
+                ["10"; 7].join(" ").as_str(),
+                ["1"; 30].join(" ").as_str(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_from_str() {
+        for format in Format::ALL {
+            assert_eq!(format.to_string().parse(), Ok(format));
+        }
+        assert!("png".parse::<Format>().is_err());
+    }
+}
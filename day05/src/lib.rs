@@ -0,0 +1,309 @@
+mod generator;
+mod heatmap;
+mod raster;
+mod sweep;
+
+pub use crate::heatmap::{Format, Heatmap, TooManyCells, MAX_CELLS};
+pub use crate::raster::Rasterization;
+pub use crate::sweep::{find_overlaps, Overlaps};
+use geometry::Vector;
+use solution::{ParseError, Solution};
+use std::collections::HashMap;
+use std::fmt;
+
+type Coord = isize;
+type Vec2 = Vector<2>;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Line {
+    start: Vec2,
+    end: Vec2,
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{} -> {},{}",
+            self.start.x(),
+            self.start.y(),
+            self.end.x(),
+            self.end.y()
+        )
+    }
+}
+
+impl Line {
+    fn slope(&self) -> Vec2 {
+        (self.end - self.start).direction()
+    }
+
+    fn is_orthogonal(&self) -> bool {
+        let slope = self.slope();
+        slope.x() == 0 || slope.y() == 0
+    }
+}
+
+struct LineIterator {
+    cur: Vec2,
+    end: Vec2,
+    slope: Vec2,
+    done: bool,
+}
+
+impl LineIterator {
+    fn new(line: &Line) -> Self {
+        Self {
+            cur: line.start,
+            end: line.end,
+            slope: line.slope(),
+            done: false,
+        }
+    }
+}
+
+impl Iterator for LineIterator {
+    type Item = Vec2;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let ret = self.cur;
+        self.cur += self.slope;
+        self.done = ret == self.end;
+
+        Some(ret)
+    }
+}
+
+impl Line {
+    fn iter(&self) -> LineIterator {
+        LineIterator::new(self)
+    }
+
+    fn points(&self, rasterization: Rasterization) -> Vec<Vec2> {
+        match rasterization {
+            Rasterization::Lattice => self.iter().collect(),
+            Rasterization::Bresenham => raster::bresenham(self.start, self.end),
+            Rasterization::Supercover => raster::supercover(self.start, self.end),
+        }
+    }
+}
+
+/// The number of lines covering each point that any of them cover.
+pub fn get_hits<'a>(
+    lines: impl IntoIterator<Item = &'a Line>,
+    rasterization: Rasterization,
+) -> HashMap<Vec2, usize> {
+    let mut points: HashMap<Vec2, usize> = Default::default();
+
+    for line in lines {
+        for point in line.points(rasterization) {
+            *points.entry(point).or_insert(0) += 1;
+        }
+    }
+
+    points
+}
+
+fn get_overlapping_points<'a>(
+    lines: impl IntoIterator<Item = &'a Line>,
+    rasterization: Rasterization,
+) -> Vec<Vec2> {
+    get_hits(lines, rasterization)
+        .iter()
+        .filter_map(|(point, &hits)| if hits >= 2 { Some(point) } else { None })
+        .copied()
+        .collect()
+}
+
+/// Counts the points covered by more than one line, with lines at any angle covering the points
+/// that the rasterization gives them. Lattice points are counted without visiting them, so that
+/// works for lines of any length.
+pub fn count_overlapping_points(lines: &[Line], rasterization: Rasterization) -> usize {
+    match rasterization {
+        Rasterization::Lattice => find_overlaps(lines).count,
+        _ => get_overlapping_points(lines, rasterization).len(),
+    }
+}
+
+peg::parser! {
+    pub grammar line_parser() for str {
+        pub rule parse() -> Vec<Line>
+            = lines:parse_lines() "\n" {
+                lines
+            }
+
+        rule parse_lines() -> Vec<Line>
+            = parse_line() ** "\n"
+
+        rule parse_line() -> Line
+            = start:parse_point() " -> " end:parse_point() {
+                Line {start, end}
+            }
+
+        rule parse_point() -> Vec2
+            = x:parse_coord() "," y:parse_coord() {
+                Vec2::new(x, y)
+            }
+
+        rule parse_coord() -> Coord
+            = s:$(['0'..='9']+) {?
+                s.parse().or(Err("coordinate"))
+            }
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = Vec<Line>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        line_parser::parse(input).map_err(|error| ParseError::from_peg(input, error))
+    }
+
+    fn solve_part_a(lines: &Self::Input<'_>) -> Self::AnswerA {
+        let orthogonal: Vec<Line> = lines
+            .iter()
+            .filter(|line| line.is_orthogonal())
+            .copied()
+            .collect();
+        find_overlaps(&orthogonal).count
+    }
+
+    fn solve_part_b(lines: &Self::Input<'_>) -> Self::AnswerB {
+        find_overlaps(lines).count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_line_slope() {
+        assert_eq!(
+            Line {
+                start: Vec2::new(0, 0),
+                end: Vec2::new(5, 0)
+            }
+            .slope(),
+            Vec2::new(1, 0)
+        );
+        assert_eq!(
+            Line {
+                start: Vec2::new(0, 0),
+                end: Vec2::new(0, 5)
+            }
+            .slope(),
+            Vec2::new(0, 1)
+        );
+        assert_eq!(
+            Line {
+                start: Vec2::new(5, 0),
+                end: Vec2::new(0, 0)
+            }
+            .slope(),
+            Vec2::new(-1, 0)
+        );
+        assert_eq!(
+            Line {
+                start: Vec2::new(0, 5),
+                end: Vec2::new(0, 0)
+            }
+            .slope(),
+            Vec2::new(0, -1)
+        );
+        assert_eq!(
+            Line {
+                start: Vec2::new(0, 0),
+                end: Vec2::new(5, 5)
+            }
+            .slope(),
+            Vec2::new(1, 1)
+        );
+        assert_eq!(
+            Line {
+                start: Vec2::new(5, 5),
+                end: Vec2::new(0, 0)
+            }
+            .slope(),
+            Vec2::new(-1, -1)
+        );
+    }
+
+    #[test]
+    fn test_line_iter() {
+        assert_eq!(
+            Line {
+                start: Vec2::new(0, 0),
+                end: Vec2::new(2, 2)
+            }
+            .iter()
+            .collect::<Vec<Vec2>>(),
+            vec![Vec2::new(0, 0), Vec2::new(1, 1), Vec2::new(2, 2)]
+        );
+        assert_eq!(
+            Line {
+                start: Vec2::new(2, 2),
+                end: Vec2::new(0, 0)
+            }
+            .iter()
+            .collect::<Vec<Vec2>>(),
+            vec![Vec2::new(2, 2), Vec2::new(1, 1), Vec2::new(0, 0),]
+        );
+    }
+
+    #[test]
+    fn test_rasterizations() {
+        let lines = Day05::parse("0,0 -> 4,2\n0,1 -> 1,1\n3,1 -> 3,1\n").unwrap();
+        let counts: Vec<usize> = Rasterization::ALL
+            .iter()
+            .map(|&rasterization| count_overlapping_points(&lines, rasterization))
+            .collect();
+        // The slanted line only goes exactly through 0,0, 2,1 and 4,2. Bresenham also covers 1,1,
+        // and the supercover covers 3,1 as well.
+        assert_eq!(counts, [0, 1, 2]);
+
+        // Lines the puzzle can give are covered the same way by the lattice and Bresenham.
+        for seed in 0..10 {
+            let generated = solution::generate::<Day05>(seed, 50);
+            let lines = Day05::parse(&generated.input).unwrap();
+            assert_eq!(
+                count_overlapping_points(&lines, Rasterization::Bresenham),
+                Day05::solve_part_b(&lines)
+            );
+        }
+    }
+
+    #[test]
+    fn test_example() {
+        let input = fs::read_to_string("input-test").unwrap();
+        let lines = Day05::parse(&input).unwrap();
+        assert_eq!(Day05::solve_part_a(&lines), 5);
+        assert_eq!(Day05::solve_part_b(&lines), 12);
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let generated = solution::generate::<Day05>(seed, 50);
+            let lines = Day05::parse(&generated.input).unwrap();
+            assert_eq!(lines.len(), 50);
+
+            for line in lines.iter() {
+                let slope = line.slope();
+                assert_ne!(slope, Default::default());
+                assert!(slope.iter().all(|c| c.abs() <= 1));
+            }
+
+            assert!(Day05::solve_part_a(&lines) <= Day05::solve_part_b(&lines));
+        }
+    }
+}
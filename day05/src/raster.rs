@@ -0,0 +1,167 @@
+use crate::{Coord, Vec2};
+use std::fmt;
+use std::str::FromStr;
+
+/// How a line is turned into the points it covers, treating each point as the square around it.
+/// They all agree on horizontal and vertical lines, and all but the supercover agree on diagonal
+/// lines at 45 degrees.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Rasterization {
+    /// Only the points that the line goes exactly through.
+    #[default]
+    Lattice,
+    /// One point for each step along the longer axis, closest to the line. Where the line runs
+    /// exactly between two points, it goes with the one further along the shorter axis.
+    Bresenham,
+    /// Every point whose square the line passes through, including both of the squares on either
+    /// side of a corner that it passes exactly through.
+    Supercover,
+}
+
+impl Rasterization {
+    pub const ALL: [Rasterization; 3] = [
+        Rasterization::Lattice,
+        Rasterization::Bresenham,
+        Rasterization::Supercover,
+    ];
+}
+
+impl fmt::Display for Rasterization {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rasterization::Lattice => write!(f, "lattice"),
+            Rasterization::Bresenham => write!(f, "bresenham"),
+            Rasterization::Supercover => write!(f, "supercover"),
+        }
+    }
+}
+
+impl FromStr for Rasterization {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|rasterization| rasterization.to_string() == s)
+            .ok_or_else(|| format!("unknown rasterization: {}", s))
+    }
+}
+
+pub fn bresenham(start: Vec2, end: Vec2) -> Vec<Vec2> {
+    let (dx, dy) = ((end.x() - start.x()).abs(), -(end.y() - start.y()).abs());
+    let (sx, sy) = (
+        (end.x() - start.x()).signum(),
+        (end.y() - start.y()).signum(),
+    );
+
+    let mut points: Vec<Vec2> = Default::default();
+    let (mut x, mut y) = (start.x(), start.y());
+    let mut error = dx + dy;
+
+    loop {
+        points.push(Vec2::new(x, y));
+
+        if x == end.x() && y == end.y() {
+            break;
+        }
+
+        let doubled = 2 * error;
+        if doubled >= dy {
+            error += dy;
+            x += sx;
+        }
+        if doubled <= dx {
+            error += dx;
+            y += sy;
+        }
+    }
+
+    points
+}
+
+// Walks from square to square, going along whichever axis the line crosses a square's edge on
+// first. Comparing where the next crossings are is done with both sides scaled up to integers.
+pub fn supercover(start: Vec2, end: Vec2) -> Vec<Vec2> {
+    let (nx, ny): (Coord, Coord) = ((end.x() - start.x()).abs(), (end.y() - start.y()).abs());
+    let (sx, sy) = (
+        (end.x() - start.x()).signum(),
+        (end.y() - start.y()).signum(),
+    );
+
+    let mut points = vec![start];
+    let (mut x, mut y) = (start.x(), start.y());
+    let (mut ix, mut iy) = (0, 0);
+
+    while ix < nx || iy < ny {
+        let crossing_x = (1 + 2 * ix) * ny;
+        let crossing_y = (1 + 2 * iy) * nx;
+
+        if crossing_x == crossing_y {
+            points.push(Vec2::new(x + sx, y));
+            points.push(Vec2::new(x, y + sy));
+            x += sx;
+            y += sy;
+            ix += 1;
+            iy += 1;
+        } else if crossing_x < crossing_y {
+            x += sx;
+            ix += 1;
+        } else {
+            y += sy;
+            iy += 1;
+        }
+
+        points.push(Vec2::new(x, y));
+    }
+
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(coords: &[(Coord, Coord)]) -> Vec<Vec2> {
+        coords.iter().map(|&(x, y)| Vec2::new(x, y)).collect()
+    }
+
+    #[test]
+    fn test_bresenham() {
+        assert_eq!(
+            bresenham(Vec2::new(0, 0), Vec2::new(4, 2)),
+            points(&[(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)])
+        );
+        assert_eq!(
+            bresenham(Vec2::new(1, 5), Vec2::new(2, 1)),
+            points(&[(1, 5), (1, 4), (2, 3), (2, 2), (2, 1)])
+        );
+        assert_eq!(
+            bresenham(Vec2::new(3, 3), Vec2::new(3, 3)),
+            points(&[(3, 3)])
+        );
+    }
+
+    #[test]
+    fn test_supercover() {
+        assert_eq!(
+            supercover(Vec2::new(0, 0), Vec2::new(4, 2)),
+            points(&[(0, 0), (1, 0), (1, 1), (2, 1), (3, 1), (3, 2), (4, 2)])
+        );
+        assert_eq!(
+            supercover(Vec2::new(2, 2), Vec2::new(1, 1)),
+            points(&[(2, 2), (1, 2), (2, 1), (1, 1)])
+        );
+        assert_eq!(
+            supercover(Vec2::new(0, 0), Vec2::new(0, 2)),
+            points(&[(0, 0), (0, 1), (0, 2)])
+        );
+    }
+
+    #[test]
+    fn test_rasterization_from_str() {
+        for rasterization in Rasterization::ALL {
+            assert_eq!(rasterization.to_string().parse(), Ok(rasterization));
+        }
+        assert!("dda".parse::<Rasterization>().is_err());
+    }
+}
@@ -0,0 +1,312 @@
+use crate::{Coord, Line, Vec2};
+use geometry::BoundingBox;
+use std::collections::{HashMap, HashSet};
+
+/// The points covered by more than one line, found without visiting the points themselves.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Overlaps {
+    pub count: usize,
+    /// The stretches where lines run along each other, followed by the single points where lines
+    /// cross, as lines that start and end at the same point. Each part is in order.
+    pub segments: Vec<Line>,
+}
+
+fn cross(a: Vec2, b: Vec2) -> Coord {
+    a.x() * b.y() - a.y() * b.x()
+}
+
+// The direction of a line, pointing right or else down, so that lines along each other have the
+// same direction no matter which way they were given. A line that's just a point is treated as
+// going right.
+fn get_direction(line: &Line) -> Vec2 {
+    let slope = line.slope();
+
+    if slope == Vec2::default() {
+        Vec2::new(1, 0)
+    } else if slope.x() < 0 || (slope.x() == 0 && slope.y() < 0) {
+        -slope
+    } else {
+        slope
+    }
+}
+
+// Lines that lie on the same infinite line, whose points are all some number of steps in the
+// direction away from the origin.
+#[derive(Debug, Default)]
+struct Group {
+    direction: Vec2,
+    origin: Vec2,
+    // The range of steps that each line covers, and the ranges covered more than once.
+    spans: Vec<(Coord, Coord)>,
+    overlaps: Vec<(Coord, Coord)>,
+}
+
+impl Group {
+    fn steps(&self, point: Vec2) -> Coord {
+        (point - self.origin).dot(&self.direction) / self.direction.dot(&self.direction)
+    }
+
+    fn point(&self, steps: Coord) -> Vec2 {
+        self.origin + self.direction * steps
+    }
+
+    // Sweeps along the line, keeping count of how many lines cover each step.
+    fn find_overlaps(&mut self) {
+        let mut events: Vec<(Coord, isize)> = self
+            .spans
+            .iter()
+            .flat_map(|&(start, end)| [(start, 1), (end + 1, -1)])
+            .collect();
+        events.sort_unstable();
+
+        let mut depth = 0;
+        let mut start = None;
+
+        for (steps, change) in events {
+            depth += change;
+
+            match start {
+                None if depth >= 2 => start = Some(steps),
+                Some(from) if depth < 2 => {
+                    self.overlaps.push((from, steps - 1));
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn overlaps_at(&self, steps: Coord) -> bool {
+        let i = self.overlaps.partition_point(|&(_, end)| end < steps);
+        self.overlaps
+            .get(i)
+            .is_some_and(|&(start, _)| start <= steps)
+    }
+}
+
+// Where two lines on different infinite lines cross at a lattice point, as the steps along each
+// of their groups.
+fn find_crossing(
+    a: &Group,
+    (start_a, end_a): (Coord, Coord),
+    b: &Group,
+    (start_b, end_b): (Coord, Coord),
+) -> Option<(Coord, Coord)> {
+    let denominator = cross(a.direction, b.direction);
+
+    if denominator == 0 {
+        return None;
+    }
+
+    // Solving for how many steps along each line the crossing is, from the line's start.
+    let offset = b.point(start_b) - a.point(start_a);
+    let (steps_a, steps_b) = (cross(offset, b.direction), cross(offset, a.direction));
+
+    if steps_a % denominator != 0 || steps_b % denominator != 0 {
+        return None;
+    }
+
+    let (steps_a, steps_b) = (steps_a / denominator, steps_b / denominator);
+
+    if !(0..=end_a - start_a).contains(&steps_a) || !(0..=end_b - start_b).contains(&steps_b) {
+        return None;
+    }
+
+    Some((start_a + steps_a, start_b + steps_b))
+}
+
+/// Finds where lines overlap by sweeping along each infinite line that more than one of them lies
+/// on, and by sweeping across the lines from left to right to work out where the ones that aren't
+/// parallel cross. Only lattice points count, so a crossing between two lattice points isn't an
+/// overlap. The memory it takes depends on the number of lines and crossings, not on how long the
+/// lines are.
+///
+/// The sweep across only compares lines that are both under it at some point, and whose heights
+/// overlap there too, which keeps it close to linear for short or sparse lines. Lines that all
+/// span most of the width still get compared with each other, so the worst case, like the real
+/// input's long lines, takes time that grows with the square of the number of lines.
+pub fn find_overlaps(lines: &[Line]) -> Overlaps {
+    let mut groups: Vec<Group> = Default::default();
+    let mut group_indexes: HashMap<(Vec2, Coord), usize> = Default::default();
+    // The group of each line, and the range of steps it covers in it.
+    let mut placements: Vec<(usize, Coord, Coord)> = Default::default();
+
+    for line in lines {
+        let direction = get_direction(line);
+        let key = (direction, cross(direction, line.start));
+
+        let index = *group_indexes.entry(key).or_insert_with(|| {
+            groups.push(Group {
+                direction,
+                origin: line.start,
+                ..Default::default()
+            });
+            groups.len() - 1
+        });
+
+        let group = &mut groups[index];
+        let (a, b) = (group.steps(line.start), group.steps(line.end));
+        let span = (a.min(b), a.max(b));
+        group.spans.push(span);
+        placements.push((index, span.0, span.1));
+    }
+
+    let mut overlaps: Overlaps = Default::default();
+
+    for group in groups.iter_mut() {
+        group.find_overlaps();
+
+        for &(start, end) in group.overlaps.iter() {
+            overlaps.count += (end - start + 1) as usize;
+            overlaps.segments.push(Line {
+                start: group.point(start),
+                end: group.point(end),
+            });
+        }
+    }
+
+    let mut crossings: HashSet<Vec2> = Default::default();
+    let mut counted: HashMap<Vec2, HashSet<usize>> = Default::default();
+
+    // Lines join the sweep at their left end and leave it once it's past their right end.
+    let bounds: Vec<BoundingBox<2>> = lines
+        .iter()
+        .map(|line| BoundingBox::new(line.start, line.end))
+        .collect();
+    let mut order: Vec<usize> = (0..lines.len()).collect();
+    order.sort_unstable_by_key(|&i| bounds[i].min.x());
+    let mut active: Vec<usize> = Default::default();
+
+    for i in order {
+        active.retain(|&j| bounds[j].max.x() >= bounds[i].min.x());
+
+        for &j in active.iter() {
+            if bounds[j].max.y() < bounds[i].min.y() || bounds[i].max.y() < bounds[j].min.y() {
+                continue;
+            }
+
+            let (group_a, start_a, end_a) = placements[j];
+            let (group_b, start_b, end_b) = placements[i];
+            let (a, b) = (&groups[group_a], &groups[group_b]);
+
+            let (steps_a, steps_b) = match find_crossing(a, (start_a, end_a), b, (start_b, end_b)) {
+                Some(steps) => steps,
+                None => continue,
+            };
+
+            // Points where lines already run along each other have been counted once for each
+            // infinite line they're on, and any other line through one of them will cross one of
+            // those lines there too.
+            let point = a.point(steps_a);
+            let mut counted_by = Vec::with_capacity(2);
+            if a.overlaps_at(steps_a) {
+                counted_by.push(group_a);
+            }
+            if b.overlaps_at(steps_b) {
+                counted_by.push(group_b);
+            }
+
+            if counted_by.is_empty() {
+                crossings.insert(point);
+            } else {
+                counted.entry(point).or_default().extend(counted_by);
+            }
+        }
+
+        active.push(i);
+    }
+
+    crossings.retain(|point| !counted.contains_key(point));
+    overlaps.count += crossings.len();
+    overlaps.count -= counted
+        .values()
+        .map(|groups| groups.len() - 1)
+        .sum::<usize>();
+    overlaps
+        .segments
+        .sort_unstable_by_key(|line| (line.start, line.end));
+
+    let mut crossings: Vec<Vec2> = crossings.into_iter().collect();
+    crossings.sort_unstable();
+    overlaps
+        .segments
+        .extend(crossings.into_iter().map(|point| Line {
+            start: point,
+            end: point,
+        }));
+
+    overlaps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{get_overlapping_points, Day05, Rasterization};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use solution::Solution;
+
+    fn parse(s: &str) -> Vec<Line> {
+        Day05::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_find_overlaps() {
+        let lines = parse("0,0 -> 6,0\n8,0 -> 3,0\n4,4 -> 4,0\n0,3 -> 6,0\n9,0 -> 9,0\n");
+        let overlaps = find_overlaps(&lines);
+
+        // 3,0 to 6,0 is covered twice, and 4,0 three times. The slanted line only goes through
+        // 6,0 and 4,1 as well as its ends.
+        assert_eq!(overlaps.count, 5);
+        let segments: Vec<String> = overlaps
+            .segments
+            .iter()
+            .map(|line| line.to_string())
+            .collect();
+        assert_eq!(segments, ["3,0 -> 6,0", "4,1 -> 4,1"]);
+    }
+
+    fn assert_matches_points(lines: &[Line]) {
+        assert_eq!(
+            find_overlaps(lines).count,
+            get_overlapping_points(lines, Rasterization::Lattice).len()
+        );
+    }
+
+    #[test]
+    fn test_find_overlaps_against_points() {
+        for seed in 0..20 {
+            let generated = solution::generate::<Day05>(seed, 100);
+            assert_matches_points(&Day05::parse(&generated.input).unwrap());
+        }
+
+        // Lines at other angles, which only meet at some of the lattice points along them, crossing
+        // where other lines run along each other.
+        assert_matches_points(&parse(concat!(
+            "0,0 -> 6,6\n6,0 -> 0,6\n3,0 -> 3,6\n3,1 -> 3,4\n0,3 -> 5,3\n1,3 -> 4,3\n",
+            "0,0 -> 6,3\n2,1 -> 8,4\n1,4 -> 7,1\n0,2 -> 9,5\n4,0 -> 4,4\n",
+        )));
+
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..20 {
+            let lines: Vec<Line> = (0..50)
+                .map(|_| {
+                    let mut point = || Vec2::new(rng.gen_range(0..20), rng.gen_range(0..20));
+                    Line {
+                        start: point(),
+                        end: point(),
+                    }
+                })
+                .collect();
+            assert_matches_points(&lines);
+        }
+    }
+
+    #[test]
+    fn test_find_overlaps_at_scale() {
+        let lines = parse("0,0 -> 4000000,0\n1000000,0 -> 9000000,0\n2000000,3 -> 2000000,0\n");
+        let overlaps = find_overlaps(&lines);
+        assert_eq!(overlaps.count, 3000001);
+        assert_eq!(overlaps.segments.len(), 1);
+    }
+}
@@ -0,0 +1,16 @@
+use crate::Day06;
+use rand::Rng;
+use solution::{Generated, Generator};
+
+/// Lanternfish timers, where the size is the number of fish. Like the real input, none of them
+/// are newborn.
+impl Generator for Day06 {
+    const DEFAULT_SIZE: usize = 300;
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+        let timers: Vec<String> = (0..size.max(1))
+            .map(|_| rng.gen_range(0..=6).to_string())
+            .collect();
+        Generated::new(timers.join(",") + "\n")
+    }
+}
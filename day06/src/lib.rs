@@ -0,0 +1,127 @@
+mod generator;
+mod lifecycle;
+
+pub use crate::lifecycle::Lifecycle;
+use num_bigint::BigUint;
+use solution::{ParseError, Solution};
+
+fn simulate_lanternfish_individually(lanternfish: &mut Vec<u8>, days: usize, lifecycle: Lifecycle) {
+    for _ in 0..days {
+        let mut new_fish: usize = 0;
+
+        for fish in lanternfish.iter_mut() {
+            if *fish == 0 {
+                *fish = lifecycle.reset;
+                new_fish += 1;
+            } else {
+                *fish -= 1;
+            }
+        }
+
+        for _ in 0..new_fish {
+            lanternfish.push(lifecycle.newborn);
+        }
+    }
+}
+
+fn simulate_lanternfish(lanternfish: &[u8], days: usize, lifecycle: Lifecycle) -> usize {
+    let mut counts = lifecycle.get_counts(lanternfish);
+
+    for _ in 0..days {
+        let new = counts.remove(0);
+        counts.push(0);
+        counts[lifecycle.reset as usize] += new;
+        counts[lifecycle.newborn as usize] += new;
+    }
+
+    counts.iter().sum()
+}
+
+/// The exact number of fish after any number of days, with fish that live by the lifecycle.
+pub fn count_lanternfish(lanternfish: &[u8], days: u64, lifecycle: Lifecycle) -> BigUint {
+    lifecycle.count(lanternfish, days)
+}
+
+fn parse_lanternfish(s: &str) -> Result<Vec<u8>, ParseError> {
+    s.trim()
+        .split(',')
+        .map(|timer| {
+            timer
+                .parse()
+                .map_err(|_| ParseError::at(s, timer, ["timer"]))
+        })
+        .collect()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = Vec<u8>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_lanternfish(input)
+    }
+
+    fn solve_part_a(lanternfish: &Self::Input<'_>) -> Self::AnswerA {
+        let mut lanternfish = lanternfish.clone();
+        simulate_lanternfish_individually(&mut lanternfish, 80, Default::default());
+        lanternfish.len()
+    }
+
+    fn solve_part_b(lanternfish: &Self::Input<'_>) -> Self::AnswerB {
+        simulate_lanternfish(lanternfish, 256, Default::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn assert_lanternfish_individually(days: usize, expected_count: usize) {
+        let input = fs::read_to_string("input-test").unwrap();
+        let mut lanternfish = parse_lanternfish(&input).unwrap();
+        simulate_lanternfish_individually(&mut lanternfish, days, Default::default());
+        assert_eq!(lanternfish.len(), expected_count);
+    }
+
+    fn assert_lanternfish(days: usize, expected_count: usize) {
+        let input = fs::read_to_string("input-test").unwrap();
+        let lanternfish = parse_lanternfish(&input).unwrap();
+        assert_eq!(
+            simulate_lanternfish(&lanternfish, days, Default::default()),
+            expected_count
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        assert_lanternfish_individually(18, 26);
+        assert_lanternfish_individually(80, 5934);
+        assert_lanternfish(18, 26);
+        assert_lanternfish(80, 5934);
+        assert_lanternfish(256, 26984457539);
+    }
+
+    #[test]
+    fn test_simulate_lanternfish_against_individual_fish() {
+        for seed in 0..50 {
+            let input = solution::generate::<Day06>(seed, 1 + seed as usize % 20).input;
+            let lanternfish = parse_lanternfish(&input).unwrap();
+            let mut individually = lanternfish.clone();
+
+            for days in 0..=80 {
+                assert_eq!(
+                    simulate_lanternfish(&lanternfish, days, Default::default()),
+                    individually.len(),
+                    "seed {} after {} days",
+                    seed,
+                    days
+                );
+                simulate_lanternfish_individually(&mut individually, 1, Default::default());
+            }
+        }
+    }
+}
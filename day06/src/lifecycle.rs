@@ -0,0 +1,176 @@
+use num_bigint::BigUint;
+
+/// The timers a lanternfish goes back to after giving birth, and that a newborn starts with. A
+/// fish gives birth on the day after its timer reaches 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lifecycle {
+    pub reset: u8,
+    pub newborn: u8,
+}
+
+impl Default for Lifecycle {
+    fn default() -> Self {
+        Self {
+            reset: 6,
+            newborn: 8,
+        }
+    }
+}
+
+impl Lifecycle {
+    /// The number of timer values a fish can have, counting from 0, which is enough for any of the
+    /// given fish as well.
+    pub fn states(&self, lanternfish: &[u8]) -> usize {
+        let timer = lanternfish.iter().copied().max().unwrap_or(0);
+        timer.max(self.reset).max(self.newborn) as usize + 1
+    }
+
+    /// Counts the fish by timer, with a count for each of the states.
+    pub fn get_counts(&self, lanternfish: &[u8]) -> Vec<usize> {
+        let mut counts = vec![0; self.states(lanternfish)];
+
+        for &fish in lanternfish.iter() {
+            counts[fish as usize] += 1;
+        }
+
+        counts
+    }
+
+    /// How a day changes the counts, where the counts after the day are the matrix times the
+    /// counts before it.
+    fn get_transition(&self, states: usize) -> Matrix {
+        let mut matrix = Matrix::zero(states);
+
+        for timer in 1..states {
+            matrix.0[timer - 1][timer] = 1u8.into();
+        }
+
+        matrix.0[self.reset as usize][0] += 1u8;
+        matrix.0[self.newborn as usize][0] += 1u8;
+
+        matrix
+    }
+
+    /// The exact number of fish after any number of days, found by raising the transition for one
+    /// day to the power of the days, so it takes a number of steps that grows with the number of
+    /// digits in the days rather than with the days themselves.
+    pub fn count(&self, lanternfish: &[u8], days: u64) -> BigUint {
+        let counts: Vec<BigUint> = self
+            .get_counts(lanternfish)
+            .into_iter()
+            .map(BigUint::from)
+            .collect();
+
+        let mut transition = self.get_transition(counts.len());
+        let mut counts = counts;
+        let mut days = days;
+
+        // The transitions for different numbers of days commute, so the counts can take each
+        // power of two as soon as it's found.
+        while days > 0 {
+            if days & 1 == 1 {
+                counts = transition.apply(&counts);
+            }
+
+            days >>= 1;
+
+            if days > 0 {
+                transition = transition.square();
+            }
+        }
+
+        counts.into_iter().sum()
+    }
+}
+
+// A square matrix, row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Matrix(Vec<Vec<BigUint>>);
+
+impl Matrix {
+    fn zero(size: usize) -> Self {
+        Self(vec![vec![Default::default(); size]; size])
+    }
+
+    fn square(&self) -> Self {
+        let size = self.0.len();
+        let mut result = Self::zero(size);
+
+        for i in 0..size {
+            for k in 0..size {
+                let a = &self.0[i][k];
+
+                // Most of the entries start out as zero, so skip the work while they are.
+                if a.bits() == 0 {
+                    continue;
+                }
+
+                for j in 0..size {
+                    result.0[i][j] += a * &self.0[k][j];
+                }
+            }
+        }
+
+        result
+    }
+
+    fn apply(&self, vector: &[BigUint]) -> Vec<BigUint> {
+        self.0
+            .iter()
+            .map(|row| row.iter().zip(vector.iter()).map(|(a, b)| a * b).sum())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{simulate_lanternfish, simulate_lanternfish_individually, Day06};
+    use solution::Solution;
+
+    #[test]
+    fn test_count_against_simulation() {
+        let lanternfish = Day06::parse("3,4,3,1,2\n").unwrap();
+        let lifecycle: Lifecycle = Default::default();
+
+        for days in 0..=256 {
+            assert_eq!(
+                lifecycle.count(&lanternfish, days),
+                simulate_lanternfish(&lanternfish, days as usize, lifecycle).into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_other_lifecycles() {
+        let lanternfish = Day06::parse("3,4,3,1,2,9\n").unwrap();
+
+        for (reset, newborn) in [(1, 3), (2, 5), (5, 2), (6, 6), (9, 1)] {
+            let lifecycle = Lifecycle { reset, newborn };
+            let mut individually = lanternfish.clone();
+
+            for days in 0..=40 {
+                assert_eq!(
+                    lifecycle.count(&lanternfish, days),
+                    individually.len().into(),
+                    "{:?} after {} days",
+                    lifecycle,
+                    days
+                );
+                simulate_lanternfish_individually(&mut individually, 1, lifecycle);
+            }
+        }
+    }
+
+    #[test]
+    fn test_count_past_usize() {
+        let lanternfish = Day06::parse("3,4,3,1,2\n").unwrap();
+        let lifecycle: Lifecycle = Default::default();
+        let count = |days| lifecycle.count(&lanternfish, days);
+
+        // Every fish alive on a day was either alive a week before, or was born to one of them
+        // two days before that.
+        assert_eq!(count(10000), count(9993) + count(9991));
+        assert!(count(10000).bits() > 1000);
+    }
+}
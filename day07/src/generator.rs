@@ -0,0 +1,32 @@
+use crate::Day07;
+use rand::Rng;
+use solution::{Generated, Generator};
+
+const MAX_POSITION: usize = 2000;
+
+/// Crab positions, where the size is the number of crabs. The positions cluster around a few
+/// points, like they do in the real input, so that the best position isn't always in the middle.
+impl Generator for Day07 {
+    const DEFAULT_SIZE: usize = 1000;
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+        let clusters: Vec<usize> = (0..rng.gen_range(1..=3))
+            .map(|_| rng.gen_range(0..=MAX_POSITION))
+            .collect();
+
+        let positions: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let center = clusters[rng.gen_range(0..clusters.len())];
+                let spread = rng.gen_range(0..=MAX_POSITION / 4);
+                let position = if rng.gen() {
+                    center.saturating_sub(rng.gen_range(0..=spread))
+                } else {
+                    (center + rng.gen_range(0..=spread)).min(MAX_POSITION)
+                };
+                position.to_string()
+            })
+            .collect();
+
+        Generated::new(positions.join(",") + "\n")
+    }
+}
@@ -0,0 +1,186 @@
+mod generator;
+
+use solution::{ParseError, Solution};
+use std::cmp::min;
+
+fn get_diff(a: usize, b: usize) -> usize {
+    a.abs_diff(b)
+}
+
+fn get_median(values: &[usize]) -> Option<usize> {
+    if values.is_empty() {
+        return None;
+    }
+
+    let mut values: Vec<&usize> = values.iter().collect();
+    values.sort_unstable();
+
+    Some(*values[values.len() / 2])
+}
+
+fn get_mean(values: &[usize]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+
+    Some(values.iter().sum::<usize>() as f64 / values.len() as f64)
+}
+
+fn get_nth_triangular_number(n: usize) -> usize {
+    n * (n + 1) / 2
+}
+
+// The burn rate gives the fuel needed to move a crab a given distance.
+fn get_fuel_cost_for_position<F>(
+    initial_positions: &[usize],
+    target_position: usize,
+    burn_rate: F,
+) -> usize
+where
+    F: Fn(usize) -> usize,
+{
+    let mut fuel_cost = 0;
+
+    for &position in initial_positions.iter() {
+        fuel_cost += burn_rate(get_diff(position, target_position));
+    }
+
+    fuel_cost
+}
+
+// When every step costs the same, the total distance is minimized at the median.
+fn get_fuel_cost_at_median(positions: &[usize]) -> usize {
+    if positions.is_empty() {
+        return 0;
+    }
+
+    let median = get_median(positions).unwrap();
+
+    get_fuel_cost_for_position(positions, median, |distance| distance)
+}
+
+// When every step costs one more than the last, the cost grows with the square of the distance,
+// so the best position is within half a step of the mean.
+fn get_fuel_cost_near_mean(positions: &[usize]) -> usize {
+    if positions.is_empty() {
+        return 0;
+    }
+
+    let mean = get_mean(positions).unwrap();
+
+    min(
+        get_fuel_cost_for_position(positions, mean.floor() as usize, get_nth_triangular_number),
+        get_fuel_cost_for_position(positions, mean.ceil() as usize, get_nth_triangular_number),
+    )
+}
+
+fn parse_positions(s: &str) -> Result<Vec<usize>, ParseError> {
+    s.trim()
+        .split(',')
+        .map(|position| {
+            position
+                .parse()
+                .map_err(|_| ParseError::at(s, position, ["position"]))
+        })
+        .collect()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = Vec<usize>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_positions(input)
+    }
+
+    fn solve_part_a(positions: &Self::Input<'_>) -> Self::AnswerA {
+        get_fuel_cost_at_median(positions)
+    }
+
+    fn solve_part_b(positions: &Self::Input<'_>) -> Self::AnswerB {
+        get_fuel_cost_near_mean(positions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    // Tries every position from the lowest crab to the highest.
+    fn get_fuel_cost_by_scanning<F>(positions: &[usize], burn_rate: F) -> usize
+    where
+        F: Fn(usize) -> usize + Copy,
+    {
+        let lowest = *positions.iter().min().unwrap();
+        let highest = *positions.iter().max().unwrap();
+
+        (lowest..=highest)
+            .map(|target| get_fuel_cost_for_position(positions, target, burn_rate))
+            .min()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_get_diff() {
+        assert_eq!(get_diff(0, 0), 0);
+        assert_eq!(get_diff(1, 0), 1);
+        assert_eq!(get_diff(1, 1), 0);
+        assert_eq!(get_diff(0, 1), 1);
+    }
+
+    #[test]
+    fn test_get_median() {
+        assert_eq!(get_median(&[]), None);
+        assert_eq!(get_median(&[1]), Some(1));
+        assert_eq!(get_median(&[1, 2]), Some(2));
+        assert_eq!(get_median(&[0, 4, 2, 1, 3]), Some(2));
+    }
+
+    #[test]
+    fn test_get_mean() {
+        assert_eq!(get_mean(&[]), None);
+        assert_eq!(get_mean(&[1]), Some(1.0));
+        assert_eq!(get_mean(&[1, 2]), Some(1.5));
+        assert_eq!(get_mean(&[0, 4, 2, 1, 3]), Some(2.0));
+    }
+
+    #[test]
+    fn test_get_nth_triangle_numbers() {
+        assert_eq!(get_nth_triangular_number(0), 0);
+        assert_eq!(get_nth_triangular_number(1), 1);
+        assert_eq!(get_nth_triangular_number(4), 10);
+    }
+
+    #[test]
+    fn test_example() {
+        let input = fs::read_to_string("input-test").unwrap();
+        let positions = Day07::parse(&input).unwrap();
+        assert_eq!(Day07::solve_part_a(&positions), 37);
+        assert_eq!(Day07::solve_part_b(&positions), 168);
+    }
+
+    #[test]
+    fn test_fuel_costs_against_scanning() {
+        for seed in 0..200 {
+            let input = solution::generate::<Day07>(seed, 1 + seed as usize % 50).input;
+            let positions = parse_positions(&input).unwrap();
+
+            assert_eq!(
+                get_fuel_cost_at_median(&positions),
+                get_fuel_cost_by_scanning(&positions, |distance| distance),
+                "seed {}",
+                seed
+            );
+            assert_eq!(
+                get_fuel_cost_near_mean(&positions),
+                get_fuel_cost_by_scanning(&positions, get_nth_triangular_number),
+                "seed {}",
+                seed
+            );
+        }
+    }
+}
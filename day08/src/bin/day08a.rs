@@ -0,0 +1,15 @@
+use day08::Day08;
+use solution::Solution;
+use std::fs;
+use std::process;
+
+fn main() {
+    let input = fs::read_to_string("input").unwrap();
+    match Day08::parse(&input) {
+        Ok(parsed) => println!("{}", Day08::solve_part_a(&parsed)),
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    }
+}
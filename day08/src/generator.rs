@@ -0,0 +1,62 @@
+use crate::Day08;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde_json::json;
+use solution::{Generated, Generator};
+
+const SEGMENTS: &str = "abcdefg";
+
+// The segments that are lit for each digit when the display is wired correctly.
+const DIGITS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+fn scramble<R: Rng + ?Sized>(rng: &mut R, wiring: &[char], digit: usize) -> String {
+    let mut segments: Vec<char> = DIGITS[digit]
+        .chars()
+        .map(|c| wiring[SEGMENTS.find(c).unwrap()])
+        .collect();
+    segments.shuffle(rng);
+    segments.into_iter().collect()
+}
+
+/// Entries for displays that are each wired randomly, where the size is the number of entries.
+/// The truth is the value shown on each display's output.
+impl Generator for Day08 {
+    const DEFAULT_SIZE: usize = 200;
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+        let mut input = String::new();
+        let mut outputs: Vec<u64> = Default::default();
+
+        for _ in 0..size {
+            let mut wiring: Vec<char> = SEGMENTS.chars().collect();
+            wiring.shuffle(rng);
+
+            let mut digits: Vec<usize> = (0..DIGITS.len()).collect();
+            digits.shuffle(rng);
+            let patterns: Vec<String> = digits
+                .into_iter()
+                .map(|digit| scramble(rng, &wiring, digit))
+                .collect();
+
+            let output: Vec<usize> = (0..4).map(|_| rng.gen_range(0..DIGITS.len())).collect();
+            let output_patterns: Vec<String> = output
+                .iter()
+                .map(|&digit| scramble(rng, &wiring, digit))
+                .collect();
+
+            input += &format!("{} | {}\n", patterns.join(" "), output_patterns.join(" "));
+            outputs.push(
+                output
+                    .iter()
+                    .fold(0, |value, &digit| value * 10 + digit as u64),
+            );
+        }
+
+        Generated {
+            input,
+            truth: Some(json!({ "outputs": outputs })),
+        }
+    }
+}
@@ -1,5 +1,7 @@
+mod generator;
+
+use solution::{ParseError, Solution};
 use std::collections::HashMap;
-use std::fs;
 
 type Digit = u8;
 type Pattern = u8;
@@ -10,6 +12,22 @@ pub struct Entry {
     output: Vec<Pattern>,
 }
 
+fn count_unique_digits(entries: &[Entry]) -> usize {
+    entries
+        .iter()
+        .map(|entry| {
+            entry
+                .output
+                .iter()
+                .filter(|digit| {
+                    let len = digit.count_ones();
+                    len == 2 || len == 3 || len == 4 || len == 7
+                })
+                .count()
+        })
+        .sum()
+}
+
 fn create_number(digits: &[Digit]) -> Option<u64> {
     if digits.is_empty() {
         return None;
@@ -133,21 +151,36 @@ peg::parser! {
             }
 
         rule parse_segment() -> char
-            = s:$(['a'..='g']) {
-                s.chars().next().unwrap()
+            = s:$(['a'..='g']) {?
+                s.chars().next().ok_or("segment")
             }
     }
 }
 
-fn main() {
-    let input = fs::read_to_string("input").unwrap();
-    let entries = entry_parser::parse(&input).unwrap();
-    dbg!(sum_output_values(&entries));
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Vec<Entry>;
+    type AnswerA = usize;
+    type AnswerB = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        entry_parser::parse(input).map_err(|error| ParseError::from_peg(input, error))
+    }
+
+    fn solve_part_a(entries: &Self::Input<'_>) -> Self::AnswerA {
+        count_unique_digits(entries)
+    }
+
+    fn solve_part_b(entries: &Self::Input<'_>) -> Self::AnswerB {
+        sum_output_values(entries)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs;
 
     #[test]
     fn test_create_number() {
@@ -159,7 +192,25 @@ mod tests {
     #[test]
     fn test_example() {
         let input = fs::read_to_string("input-test").unwrap();
-        let entries = entry_parser::parse(&input).unwrap();
-        assert_eq!(sum_output_values(&entries), 61229);
+        let entries = Day08::parse(&input).unwrap();
+        assert_eq!(Day08::solve_part_a(&entries), 26);
+        assert_eq!(Day08::solve_part_b(&entries), 61229);
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let generated = solution::generate::<Day08>(seed, 20);
+            let entries = Day08::parse(&generated.input).unwrap();
+            let outputs: Vec<u64> =
+                serde_json::from_value(generated.truth.unwrap()["outputs"].clone()).unwrap();
+
+            for (entry, &output) in entries.iter().zip(outputs.iter()) {
+                let mapping = deduce_signal_mapping(&entry.input);
+                assert_eq!(translate_signals(&mapping, &entry.output), output);
+            }
+
+            assert_eq!(sum_output_values(&entries), outputs.iter().sum::<u64>());
+        }
     }
 }
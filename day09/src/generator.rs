@@ -0,0 +1,77 @@
+use crate::Day09;
+use rand::Rng;
+use solution::{Generated, Generator};
+
+// How wide the square of the map around each low point is, so there's one for about every 40
+// points.
+const BASIN_WIDTH: usize = 6;
+
+// How many squares away from a point its two nearest low points can be. The low point in its own
+// square is less than two widths away, and the one in the square beside it less than three, while
+// any low point four squares away is more than three widths away.
+const SEARCH_SQUARES: usize = 3;
+
+/// A square heightmap, where the size is how wide it is. Like the real input, it's made of basins
+/// that slope up from a low point until they meet the next basin at a ridge of nines. There's a
+/// low point somewhere in each square of the map, so every point only needs to be measured
+/// against the ones nearby.
+impl Generator for Day09 {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+        let size = size.max(1);
+        let squares = size.div_ceil(BASIN_WIDTH);
+
+        let centers: Vec<Vec<(f64, f64)>> = (0..squares)
+            .map(|square_row| {
+                (0..squares)
+                    .map(|square_column| {
+                        let rows =
+                            square_row * BASIN_WIDTH..size.min((square_row + 1) * BASIN_WIDTH);
+                        let columns = square_column * BASIN_WIDTH
+                            ..size.min((square_column + 1) * BASIN_WIDTH);
+                        (rng.gen_range(rows) as f64, rng.gen_range(columns) as f64)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let nearby = |index: usize| {
+            let square = index / BASIN_WIDTH;
+            square.saturating_sub(SEARCH_SQUARES)..(square + SEARCH_SQUARES + 1).min(squares)
+        };
+
+        let mut input = String::new();
+
+        for row in 0..size {
+            for column in 0..size {
+                // These are squared until the two nearest are found.
+                let (mut nearest, mut next) = (f64::INFINITY, f64::INFINITY);
+
+                for square_row in nearby(row) {
+                    for square_column in nearby(column) {
+                        let (y, x) = centers[square_row][square_column];
+                        let distance = (y - row as f64).powi(2) + (x - column as f64).powi(2);
+                        if distance < nearest {
+                            (nearest, next) = (distance, nearest);
+                        } else if distance < next {
+                            next = distance;
+                        }
+                    }
+                }
+
+                // Points about as far from two of the low points are on the ridge between them.
+                let (nearest, next) = (nearest.sqrt(), next.sqrt());
+                let height = if next - nearest < 1.0 {
+                    9
+                } else {
+                    ((nearest * 1.5) as u32).min(8)
+                };
+                input.push(char::from_digit(height, 10).unwrap());
+            }
+            input.push('\n');
+        }
+
+        Generated::new(input)
+    }
+}
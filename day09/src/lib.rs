@@ -0,0 +1,136 @@
+mod generator;
+
+use grid::{Grid, Location, Neighborhood};
+use solution::{ParseError, Solution};
+use std::collections::HashSet;
+
+type Height = u8;
+
+#[derive(Debug, Default)]
+pub struct Heightmap {
+    points: Grid<Height>,
+}
+
+impl Heightmap {
+    fn adjacent_locations(&self, location: Location) -> Vec<Location> {
+        self.points
+            .adjacent_locations(&location, Neighborhood::Four)
+    }
+
+    fn get(&self, location: Location) -> Option<Height> {
+        self.points.get(&location).copied()
+    }
+
+    fn low_points(&self) -> Vec<Location> {
+        self.points
+            .locations()
+            .filter(|&location| {
+                let height = self.get(location).unwrap();
+                self.adjacent_locations(location)
+                    .iter()
+                    .all(|&adj_location| height < self.get(adj_location).unwrap())
+            })
+            .collect()
+    }
+
+    fn explore_basin(&self, basin: &mut HashSet<Location>, location: Location) {
+        basin.insert(location);
+
+        let height = self.get(location).unwrap();
+
+        for adj_location in self.adjacent_locations(location) {
+            if basin.contains(&adj_location) {
+                continue;
+            }
+
+            let adj_height = self.get(adj_location).unwrap();
+
+            if height < adj_height && adj_height != 9 {
+                self.explore_basin(basin, adj_location);
+            }
+        }
+    }
+
+    fn basins(&self) -> Vec<Vec<Location>> {
+        self.low_points()
+            .iter()
+            .map(|&location| {
+                let mut basin = Default::default();
+                self.explore_basin(&mut basin, location);
+                basin.into_iter().collect()
+            })
+            .collect()
+    }
+}
+
+fn sum_risk_levels(heightmap: &Heightmap) -> usize {
+    heightmap
+        .low_points()
+        .iter()
+        .map(|&location| heightmap.get(location).unwrap() as usize + 1)
+        .sum()
+}
+
+fn multiply_n_largest_basins(heightmap: &Heightmap, n: usize) -> usize {
+    let mut basin_sizes: Vec<usize> = heightmap.basins().iter().map(|basin| basin.len()).collect();
+    basin_sizes.sort_unstable();
+    basin_sizes.reverse();
+    basin_sizes.iter().take(n).product()
+}
+
+fn parse_heightmap(s: &str) -> Result<Heightmap, ParseError> {
+    Ok(Heightmap {
+        points: Grid::parse_digits(s)?,
+    })
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = Heightmap;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_heightmap(input)
+    }
+
+    fn solve_part_a(heightmap: &Self::Input<'_>) -> Self::AnswerA {
+        sum_risk_levels(heightmap)
+    }
+
+    fn solve_part_b(heightmap: &Self::Input<'_>) -> Self::AnswerB {
+        multiply_n_largest_basins(heightmap, 3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_example() {
+        let input = fs::read_to_string("input-test").unwrap();
+        let heightmap = parse_heightmap(&input).unwrap();
+        assert_eq!(sum_risk_levels(&heightmap), 15);
+        assert_eq!(multiply_n_largest_basins(&heightmap, 3), 1134);
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let generated = solution::generate::<Day09>(seed, 30);
+            let heightmap = Day09::parse(&generated.input).unwrap();
+            assert_eq!(heightmap.points.len(), 900);
+
+            let basins = heightmap.basins();
+            assert!(basins.len() >= 3);
+            for basin in basins {
+                assert!(basin
+                    .iter()
+                    .all(|&location| heightmap.get(location) != Some(9)));
+            }
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use day10::Day10;
+use solution::Solution;
+use std::fs;
+use std::process;
+
+fn main() {
+    let input = fs::read_to_string("input").unwrap();
+    match Day10::parse(&input) {
+        Ok(parsed) => println!("{}", Day10::solve_part_a(&parsed)),
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    }
+}
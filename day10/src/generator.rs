@@ -0,0 +1,103 @@
+use crate::{get_closing_char, Day10};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde_json::json;
+use solution::{Generated, Generator};
+
+const OPENING_CHARS: [char; 4] = ['(', '[', '{', '<'];
+
+// Adds a chunk with the given number of pairs of characters in all, including its own.
+fn push_chunk<R: Rng + ?Sized>(rng: &mut R, line: &mut String, pairs: usize) {
+    let open = *OPENING_CHARS.choose(rng).unwrap();
+    line.push(open);
+
+    let mut remaining = pairs - 1;
+    while remaining > 0 {
+        let inner = rng.gen_range(1..=remaining);
+        push_chunk(rng, line, inner);
+        remaining -= inner;
+    }
+
+    line.push(get_closing_char(open).unwrap());
+}
+
+fn get_illegal_score(c: char) -> usize {
+    match c {
+        ')' => 3,
+        ']' => 57,
+        '}' => 1197,
+        '>' => 25137,
+        _ => unreachable!(),
+    }
+}
+
+/// Lines of chunks that are each either corrupted or incomplete, like the real input, where the
+/// size is the number of lines. There's always an odd number of incomplete lines, so that they
+/// have a middle score. The truth is the score for the corrupted lines, and how many lines are
+/// incomplete.
+impl Generator for Day10 {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+        let mut incomplete: Vec<bool> = (0..size.max(1)).map(|_| rng.gen()).collect();
+        if incomplete.iter().filter(|&&incomplete| incomplete).count() % 2 == 0 {
+            incomplete[0] = !incomplete[0];
+        }
+
+        let mut input = String::new();
+        let mut illegal_closes = 0;
+
+        for &incomplete in incomplete.iter() {
+            let mut line = String::new();
+            let mut remaining = rng.gen_range(20..=55);
+            while remaining > 0 {
+                let pairs = rng.gen_range(1..=remaining);
+                push_chunk(rng, &mut line, pairs);
+                remaining -= pairs;
+            }
+
+            if incomplete {
+                // Cutting the line anywhere that a chunk is still open leaves it incomplete.
+                let mut depth = 0;
+                let cuts: Vec<usize> = line
+                    .chars()
+                    .enumerate()
+                    .filter_map(|(i, c)| {
+                        depth += if OPENING_CHARS.contains(&c) { 1 } else { -1 };
+                        (depth > 0).then_some(i + 1)
+                    })
+                    .collect();
+                line.truncate(*cuts.choose(rng).unwrap());
+            } else {
+                let closes: Vec<usize> = line
+                    .char_indices()
+                    .filter(|(_, c)| !OPENING_CHARS.contains(c))
+                    .map(|(i, _)| i)
+                    .collect();
+                let i = *closes.choose(rng).unwrap();
+                let expected = line[i..].chars().next().unwrap();
+                let illegal = *OPENING_CHARS
+                    .iter()
+                    .filter_map(|&open| get_closing_char(open))
+                    .filter(|&close| close != expected)
+                    .collect::<Vec<char>>()
+                    .choose(rng)
+                    .unwrap();
+
+                line.replace_range(i..=i, &illegal.to_string());
+                illegal_closes += get_illegal_score(illegal);
+            }
+
+            input += &line;
+            input.push('\n');
+        }
+
+        Generated {
+            input,
+            truth: Some(json!({
+                "illegal_closes": illegal_closes,
+                "incomplete": incomplete.iter().filter(|&&incomplete| incomplete).count(),
+            })),
+        }
+    }
+}
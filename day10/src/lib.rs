@@ -1,4 +1,6 @@
-use std::fs;
+mod generator;
+
+use solution::{ParseError, Solution};
 
 #[derive(Debug)]
 struct Location {
@@ -13,9 +15,9 @@ enum SyntaxError {
 }
 
 #[derive(Debug)]
-struct Score {
-    illegal_closes: usize,
-    incomplete_closes: usize,
+pub struct Score {
+    pub illegal_closes: usize,
+    pub incomplete_closes: usize,
 }
 
 fn get_closing_char(open: char) -> Option<char> {
@@ -60,7 +62,7 @@ fn validate(lines: &[&str]) -> Vec<SyntaxError> {
     errors
 }
 
-fn score_syntax_errors(lines: &[&str]) -> Score {
+pub fn score_syntax_errors(lines: &[&str]) -> Score {
     let errors = validate(lines);
     let mut illegal_closes = 0;
     let mut incomplete_closes_scores: Vec<usize> = Default::default();
@@ -106,16 +108,30 @@ fn parse_lines(s: &str) -> Vec<&str> {
     s.lines().collect()
 }
 
-fn main() {
-    let input = fs::read_to_string("input").unwrap();
-    let lines = parse_lines(&input);
-    let score = score_syntax_errors(&lines);
-    dbg!(score.illegal_closes, score.incomplete_closes);
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Vec<&'a str>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(parse_lines(input))
+    }
+
+    fn solve_part_a(lines: &Self::Input<'_>) -> Self::AnswerA {
+        score_syntax_errors(lines).illegal_closes
+    }
+
+    fn solve_part_b(lines: &Self::Input<'_>) -> Self::AnswerB {
+        score_syntax_errors(lines).incomplete_closes
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs;
 
     #[test]
     fn test_example() {
@@ -125,4 +141,21 @@ mod tests {
         assert_eq!(score.illegal_closes, 26397);
         assert_eq!(score.incomplete_closes, 288957);
     }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let generated = solution::generate::<Day10>(seed, 50);
+            let lines = Day10::parse(&generated.input).unwrap();
+            let truth = generated.truth.unwrap();
+
+            assert_eq!(Day10::solve_part_a(&lines), truth["illegal_closes"]);
+            let incomplete = validate(&lines)
+                .iter()
+                .filter(|error| matches!(error, SyntaxError::IncompleteClose(_)))
+                .count();
+            assert_eq!(incomplete, truth["incomplete"]);
+            assert_eq!(incomplete % 2, 1);
+        }
+    }
 }
@@ -0,0 +1,41 @@
+use crate::{Day11, OctopusGrid, MAX_ENERGY_LEVEL};
+use rand::Rng;
+use serde_json::json;
+use solution::{Generated, Generator};
+
+// Most grids much wider than this never flash all at once.
+const MAX_SIZE: usize = 20;
+
+// The real input flashes all at once after a few hundred steps.
+const STEP_LIMIT: usize = 1000;
+
+/// A square grid of energy levels, where the size is how wide it is, up to 20. Plenty of grids
+/// settle into a cycle where the octopuses never all flash at once, which would leave part b with
+/// no answer, so grids are drawn until one of them does within a thousand steps. The truth is the
+/// step where that happens.
+impl Generator for Day11 {
+    const DEFAULT_SIZE: usize = 10;
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+        let size = size.clamp(1, MAX_SIZE);
+
+        loop {
+            let mut input = String::new();
+            for _ in 0..size {
+                for _ in 0..size {
+                    input += &rng.gen_range(0..MAX_ENERGY_LEVEL).to_string();
+                }
+                input.push('\n');
+            }
+
+            let mut octopus_grid: OctopusGrid = input.parse().unwrap();
+            let all = size * size;
+            if let Some(step) = (1..=STEP_LIMIT).find(|_| octopus_grid.step().len() == all) {
+                return Generated {
+                    input,
+                    truth: Some(json!({ "synchronized": step })),
+                };
+            }
+        }
+    }
+}
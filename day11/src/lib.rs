@@ -0,0 +1,190 @@
+mod generator;
+
+use grid::{Grid, Location, Locations, Neighborhood};
+use solution::{ParseError, Solution};
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+type EnergyLevel = u8;
+
+const MAX_ENERGY_LEVEL: EnergyLevel = 10;
+
+#[derive(Debug, Default, Clone)]
+pub struct OctopusGrid {
+    energy_levels: Grid<EnergyLevel>,
+}
+
+impl OctopusGrid {
+    fn locations(&self) -> Locations {
+        self.energy_levels.locations()
+    }
+
+    fn get_energy_level(&self, location: &Location) -> Option<EnergyLevel> {
+        self.energy_levels.get(location).copied()
+    }
+
+    fn increase_energy_level(&mut self, location: &Location) {
+        if let Some(energy_level) = self.energy_levels.get_mut(location) {
+            *energy_level += 1;
+        }
+    }
+
+    fn reset_energy_level(&mut self, location: &Location) {
+        if let Some(energy_level) = self.energy_levels.get_mut(location) {
+            *energy_level = 0;
+        }
+    }
+
+    fn adjacent_locations(&self, location: &Location) -> Vec<Location> {
+        self.energy_levels
+            .adjacent_locations(location, Neighborhood::Eight)
+    }
+
+    fn step(&mut self) -> HashSet<Location> {
+        let mut flashes: HashSet<Location> = Default::default();
+
+        fn flash(
+            octopus_grid: &mut OctopusGrid,
+            flashes: &mut HashSet<Location>,
+            location: &Location,
+        ) {
+            if !flashes.contains(location)
+                && octopus_grid.get_energy_level(location).unwrap() >= MAX_ENERGY_LEVEL
+            {
+                flashes.insert(*location);
+                for adj_location in octopus_grid.adjacent_locations(location) {
+                    octopus_grid.increase_energy_level(&adj_location);
+                    flash(octopus_grid, flashes, &adj_location);
+                }
+            }
+        }
+
+        for location in self.locations() {
+            self.increase_energy_level(&location);
+        }
+
+        for location in self.locations() {
+            flash(self, &mut flashes, &location);
+        }
+
+        for location in flashes.iter() {
+            self.reset_energy_level(location);
+        }
+
+        flashes
+    }
+}
+
+impl FromStr for OctopusGrid {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            energy_levels: Grid::parse_digits(s)?,
+        })
+    }
+}
+
+impl fmt::Display for OctopusGrid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.energy_levels)
+    }
+}
+
+fn flash_count_after_n_steps(octopus_grid: &mut OctopusGrid, n: usize) -> usize {
+    let mut flash_count = 0;
+
+    for _ in 0..n {
+        flash_count += octopus_grid.step().len();
+    }
+
+    flash_count
+}
+
+fn find_simultaneous_flash_step(octopus_grid: &mut OctopusGrid) -> usize {
+    let mut step = 0;
+
+    loop {
+        step += 1;
+        if octopus_grid.step().len() == octopus_grid.energy_levels.len() {
+            return step;
+        }
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = OctopusGrid;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        OctopusGrid::from_str(input)
+    }
+
+    fn solve_part_a(octopus_grid: &Self::Input<'_>) -> Self::AnswerA {
+        flash_count_after_n_steps(&mut octopus_grid.clone(), 100)
+    }
+
+    fn solve_part_b(octopus_grid: &Self::Input<'_>) -> Self::AnswerB {
+        find_simultaneous_flash_step(&mut octopus_grid.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_octopus_grid_step() {
+        let mut octopus_grid = OctopusGrid::from_str("11111\n19991\n19191\n19991\n11111").unwrap();
+        let flashes = octopus_grid.step();
+        assert_eq!(
+            format!("{}", &octopus_grid),
+            "34543\n40004\n50005\n40004\n34543\n"
+        );
+        assert!(flashes.contains(&Location { row: 1, column: 1 }));
+        assert!(flashes.contains(&Location { row: 1, column: 2 }));
+        assert!(flashes.contains(&Location { row: 1, column: 3 }));
+        assert!(flashes.contains(&Location { row: 2, column: 1 }));
+        assert!(flashes.contains(&Location { row: 2, column: 2 }));
+        assert!(flashes.contains(&Location { row: 2, column: 3 }));
+        assert!(flashes.contains(&Location { row: 3, column: 1 }));
+        assert!(flashes.contains(&Location { row: 3, column: 2 }));
+        assert!(flashes.contains(&Location { row: 3, column: 3 }));
+
+        let flashes = octopus_grid.step();
+        assert_eq!(
+            format!("{}", &octopus_grid),
+            "45654\n51115\n61116\n51115\n45654\n"
+        );
+        assert!(flashes.is_empty());
+    }
+
+    #[test]
+    fn test_example() {
+        let input = fs::read_to_string("input-test").unwrap();
+        let octopus_grid = OctopusGrid::from_str(&input).unwrap();
+        assert_eq!(
+            flash_count_after_n_steps(&mut octopus_grid.clone(), 100),
+            1656
+        );
+        assert_eq!(find_simultaneous_flash_step(&mut octopus_grid.clone()), 195);
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..5 {
+            let generated = solution::generate::<Day11>(seed, 10);
+            let octopus_grid = Day11::parse(&generated.input).unwrap();
+            assert_eq!(octopus_grid.energy_levels.len(), 100);
+            assert_eq!(
+                Day11::solve_part_b(&octopus_grid),
+                generated.truth.unwrap()["synchronized"]
+            );
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::Day12;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use solution::{Generated, Generator};
+use std::collections::HashSet;
+
+fn cave_name<R: Rng + ?Sized>(rng: &mut R, big: bool, length: usize) -> String {
+    let letters = if big { 'A'..='Z' } else { 'a'..='z' };
+    let letters: Vec<char> = letters.collect();
+    (0..length).map(|_| *letters.choose(rng).unwrap()).collect()
+}
+
+/// A connected cave system, where the size is the number of caves besides the start and end.
+/// Two big caves are never connected, since there would be infinitely many paths between them.
+impl Generator for Day12 {
+    const DEFAULT_SIZE: usize = 12;
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+        let mut caves: Vec<(String, bool)> = vec![("start".to_string(), false)];
+        let mut names: HashSet<String> = ["start", "end"].map(String::from).into();
+
+        // Names are two letters like the real input's, unless there are too many caves for that,
+        // in which case they're long enough that there are at least twice as many names of each
+        // kind as there are caves.
+        let mut length = 2;
+        while 26usize.saturating_pow(length as u32) < size.saturating_mul(2) {
+            length += 1;
+        }
+
+        while names.len() < size + 2 {
+            let big = rng.gen_ratio(1, 3);
+            let name = cave_name(rng, big, length);
+            if names.insert(name.clone()) {
+                caves.push((name, big));
+            }
+        }
+
+        caves.push(("end".to_string(), false));
+
+        let mut connections: Vec<(usize, usize)> = Default::default();
+        let mut connected: HashSet<(usize, usize)> = Default::default();
+
+        let mut connect = |a: usize, b: usize| {
+            let key = (a.min(b), a.max(b));
+            if a == b || (caves[a].1 && caves[b].1) || !connected.insert(key) {
+                return false;
+            }
+            connections.push((a, b));
+            true
+        };
+
+        // Connect each cave to one before it, so that they're all reachable from the start.
+        for i in 1..caves.len() {
+            while !connect(i, rng.gen_range(0..i)) {}
+        }
+
+        for _ in 0..size {
+            connect(rng.gen_range(0..caves.len()), rng.gen_range(0..caves.len()));
+        }
+
+        connections.shuffle(rng);
+
+        let mut input = String::new();
+        for (a, b) in connections {
+            input += &format!("{}-{}\n", caves[a].0, caves[b].0);
+        }
+
+        Generated::new(input)
+    }
+}
@@ -0,0 +1,213 @@
+mod generator;
+
+use serde_json::{json, Value};
+use solution::{ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+type Cave<'a> = &'a str;
+type Path<'a> = Vec<Cave<'a>>;
+
+#[derive(Debug, Default)]
+pub struct CaveSystem<'a> {
+    connections: HashMap<Cave<'a>, HashSet<Cave<'a>>>,
+}
+
+impl<'a> CaveSystem<'a> {
+    fn caves(&self) -> impl Iterator<Item = Cave<'a>> + '_ {
+        self.connections.keys().copied()
+    }
+
+    fn add_connection(&mut self, a: Cave<'a>, b: Cave<'a>) {
+        self.connections.entry(a).or_default().insert(b);
+        self.connections.entry(b).or_default().insert(a);
+    }
+
+    // If revisiting isn't allowed, every path starts out as if a small cave had already been
+    // visited twice, so that every small cave is limited to one visit.
+    fn find_paths(&'a self, revisit_small_cave: bool) -> Vec<Path<'a>> {
+        let mut paths: Vec<(Path<'a>, HashMap<Cave<'a>, usize>, bool)> =
+            vec![(vec!["start"], Default::default(), !revisit_small_cave)];
+
+        let mut completed_paths: Vec<Path<'a>> = Default::default();
+
+        while let Some((path, visited, twice)) = paths.pop() {
+            let cur_cave = *path.last().unwrap();
+
+            if cur_cave == "end" {
+                completed_paths.push(path);
+                continue;
+            }
+
+            for &adj_cave in self.connections.get(cur_cave).unwrap() {
+                if adj_cave == "start" {
+                    continue;
+                }
+
+                let is_small = adj_cave.contains(char::is_lowercase);
+
+                let mut visited = visited.clone();
+                let visit_count = visited.entry(adj_cave).or_default();
+                let visit_limit = if twice { 1 } else { 2 };
+
+                if is_small && *visit_count >= visit_limit {
+                    continue;
+                }
+
+                *visit_count += 1;
+
+                let twice = twice || (is_small && *visit_count >= 2);
+
+                let mut path = path.clone();
+                path.push(adj_cave);
+
+                paths.push((path, visited, twice));
+            }
+        }
+
+        completed_paths
+    }
+}
+
+peg::parser! {
+    grammar cave_system_parser() for str {
+        pub rule parse() -> CaveSystem<'input>
+            = connections:(parse_connection() ** "\n") "\n"? {
+                let mut cave_system: CaveSystem = Default::default();
+
+                for [a, b] in connections.into_iter() {
+                    cave_system.add_connection(a, b);
+                }
+
+                cave_system
+            }
+
+        rule parse_connection() -> [Cave<'input>; 2]
+            = a:parse_cave() "-" b:parse_cave() {
+                [a, b]
+            }
+
+        rule parse_cave() -> Cave<'input>
+            = s:$(['a'..='z' | 'A'..='Z']+)
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = CaveSystem<'a>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        cave_system_parser::parse(input).map_err(|error| ParseError::from_peg(input, error))
+    }
+
+    fn solve_part_a(cave_system: &Self::Input<'_>) -> Self::AnswerA {
+        cave_system.find_paths(false).len()
+    }
+
+    fn solve_part_b(cave_system: &Self::Input<'_>) -> Self::AnswerB {
+        cave_system.find_paths(true).len()
+    }
+
+    fn extras(cave_system: &Self::Input<'_>) -> Option<Value> {
+        let small_caves = cave_system
+            .caves()
+            .filter(|cave| cave.contains(char::is_lowercase))
+            .count();
+
+        Some(json!({
+            "caves": cave_system.caves().count(),
+            "small_caves": small_caves,
+            "paths": {
+                "visiting_small_caves_once": cave_system.find_paths(false).len(),
+                "revisiting_one_small_cave": cave_system.find_paths(true).len(),
+            },
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn parse_expected_output(s: &str) -> Vec<Path<'_>> {
+        s.lines().map(|line| line.split(',').collect()).collect()
+    }
+
+    fn same_vec_items<T: Eq + std::hash::Hash>(a: Vec<T>, b: Vec<T>) -> bool {
+        let a: HashSet<T> = a.into_iter().collect();
+        let b: HashSet<T> = b.into_iter().collect();
+        a.symmetric_difference(&b).count() == 0
+    }
+
+    fn assert_paths(cave_system: &CaveSystem, revisit_small_cave: bool, output_path: &str) {
+        let output = fs::read_to_string(output_path).unwrap();
+        let actual_paths = cave_system.find_paths(revisit_small_cave);
+        let expected_paths = parse_expected_output(&output);
+        assert!(same_vec_items(actual_paths, expected_paths));
+    }
+
+    #[test]
+    fn test_small_example() {
+        let input = fs::read_to_string("input-test-small").unwrap();
+        let cave_system = cave_system_parser::parse(&input).unwrap();
+        assert_paths(&cave_system, false, "output-test-small-a");
+        assert_paths(&cave_system, true, "output-test-small-b");
+    }
+
+    #[test]
+    fn test_medium_example() {
+        let input = fs::read_to_string("input-test-medium").unwrap();
+        let cave_system = cave_system_parser::parse(&input).unwrap();
+        assert_paths(&cave_system, false, "output-test-medium-a");
+        assert_eq!(cave_system.find_paths(true).len(), 103);
+    }
+
+    #[test]
+    fn test_large_example() {
+        let input = fs::read_to_string("input-test-large").unwrap();
+        let cave_system = cave_system_parser::parse(&input).unwrap();
+        assert_eq!(cave_system.find_paths(false).len(), 226);
+        assert_eq!(cave_system.find_paths(true).len(), 3509);
+    }
+
+    #[test]
+    fn test_extras() {
+        let input = fs::read_to_string("input-test-small").unwrap();
+        let cave_system = Day12::parse(&input).unwrap();
+        assert_eq!(
+            Day12::extras(&cave_system),
+            Some(json!({
+                "caves": 6,
+                "small_caves": 5,
+                "paths": {
+                    "visiting_small_caves_once": 10,
+                    "revisiting_one_small_cave": 36,
+                },
+            }))
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let generated = solution::generate::<Day12>(seed, 8);
+            let cave_system = Day12::parse(&generated.input).unwrap();
+            assert_eq!(cave_system.caves().count(), 10);
+
+            let paths = cave_system.find_paths(false);
+            assert!(!paths.is_empty());
+            assert!(paths.len() <= cave_system.find_paths(true).len());
+        }
+    }
+
+    #[test]
+    fn test_generate_many_caves() {
+        // More caves than there are two letter names for.
+        let generated = solution::generate::<Day12>(0, 2000);
+        let cave_system = Day12::parse(&generated.input).unwrap();
+        assert_eq!(cave_system.caves().count(), 2002);
+    }
+}
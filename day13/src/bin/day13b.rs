@@ -0,0 +1,15 @@
+use day13::Day13;
+use solution::Solution;
+use std::fs;
+use std::process;
+
+fn main() {
+    let input = fs::read_to_string("input").unwrap();
+    match Day13::parse(&input) {
+        Ok(parsed) => println!("{}", Day13::solve_part_b(&parsed)),
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use crate::Day13;
+use rand::seq::index;
+use rand::Rng;
+use serde_json::json;
+use solution::{Generated, Generator};
+use std::collections::HashSet;
+
+// The size of the paper once it's been folded all the way, which is where the code shows up.
+const FOLDED_WIDTH: usize = 40;
+const FOLDED_HEIGHT: usize = 6;
+
+// Whether each fold is along a column, in the order they're made, like the real input's.
+const FOLDS: [bool; 12] = [
+    true, false, true, false, true, false, true, false, true, false, false, false,
+];
+
+/// Dots on a sheet that folds up the same way as the real input's, where the size is the number
+/// of dots, up to the 983,040 that can fold into the sheet. The dots are placed on the folded sheet
+/// first and then unfolded onto one side or the other of each fold, so none of them land on a
+/// fold. The truth is where the dots end up.
+impl Generator for Day13 {
+    const DEFAULT_SIZE: usize = 850;
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+        // Every fold is along the middle of the paper, which is twice as big plus one before it.
+        let (mut width, mut height) = (FOLDED_WIDTH, FOLDED_HEIGHT);
+        let mut folds: Vec<(bool, usize)> = Default::default();
+        for &along_column in FOLDS.iter().rev() {
+            let side = if along_column {
+                &mut width
+            } else {
+                &mut height
+            };
+            folds.push((along_column, *side));
+            *side = *side * 2 + 1;
+        }
+        folds.reverse();
+
+        // Which side of every fold a dot unfolds onto tells it apart from every other dot that
+        // folds onto the same place, so each distinct dot is one of these combinations.
+        let combinations = (FOLDED_WIDTH * FOLDED_HEIGHT) << folds.len();
+
+        let mut folded: HashSet<(usize, usize)> = Default::default();
+        let mut input = String::new();
+
+        for i in index::sample(rng, combinations, size.clamp(1, combinations)) {
+            let (cell, sides) = (
+                i % (FOLDED_WIDTH * FOLDED_HEIGHT),
+                i / (FOLDED_WIDTH * FOLDED_HEIGHT),
+            );
+            let dot = (cell % FOLDED_WIDTH, cell / FOLDED_WIDTH);
+            let (mut column, mut row) = dot;
+
+            for (j, &(along_column, line)) in folds.iter().rev().enumerate() {
+                if (sides >> j) & 1 == 1 {
+                    if along_column {
+                        column = line * 2 - column;
+                    } else {
+                        row = line * 2 - row;
+                    }
+                }
+            }
+
+            folded.insert(dot);
+            input += &format!("{},{}\n", column, row);
+        }
+
+        input.push('\n');
+        for (along_column, line) in folds {
+            input += &format!(
+                "fold along {}={}\n",
+                if along_column { 'x' } else { 'y' },
+                line
+            );
+        }
+
+        let mut folded: Vec<(usize, usize)> = folded.into_iter().collect();
+        folded.sort_unstable();
+
+        Generated {
+            input,
+            truth: Some(json!({ "dots": folded })),
+        }
+    }
+}
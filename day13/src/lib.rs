@@ -0,0 +1,207 @@
+mod generator;
+
+use grid::{Location, SparseGrid};
+use solution::{ParseError, Solution};
+use std::fmt;
+use std::ops::Add;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Fold {
+    Row(usize),
+    Column(usize),
+}
+
+impl Add<Fold> for Location {
+    type Output = Location;
+
+    fn add(self, fold: Fold) -> Self::Output {
+        let Location {
+            mut row,
+            mut column,
+        } = self;
+
+        match fold {
+            Fold::Row(fold_row) => {
+                if row > fold_row {
+                    row -= (row - fold_row) * 2;
+                }
+            }
+            Fold::Column(fold_column) => {
+                if column > fold_column {
+                    column -= (column - fold_column) * 2;
+                }
+            }
+        }
+
+        Location { row, column }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Paper {
+    dots: SparseGrid,
+}
+
+impl Add<Fold> for Paper {
+    type Output = Paper;
+
+    fn add(self, fold: Fold) -> Self::Output {
+        Paper {
+            dots: self
+                .dots
+                .into_iter()
+                .map(|location| location + fold)
+                .collect(),
+        }
+    }
+}
+
+impl Add<Vec<Fold>> for Paper {
+    type Output = Paper;
+
+    fn add(self, folds: Vec<Fold>) -> Self::Output {
+        let mut paper = self;
+
+        for fold in folds {
+            paper = paper + fold;
+        }
+
+        paper
+    }
+}
+
+impl fmt::Display for Paper {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}", self.dots)
+    }
+}
+
+peg::parser! {
+    grammar transparent_paper_parser() for str {
+        pub rule parse() -> (Paper, Vec<Fold>)
+            = dots:parse_dots() "\n\n" fold_instructions:parse_fold_instructions() "\n"? {
+                (Paper {dots}, fold_instructions)
+            }
+
+        rule parse_dots() -> SparseGrid
+            = locations:(parse_location() ** "\n") {
+                locations.into_iter().collect()
+            }
+
+        rule parse_fold_instructions() -> Vec<Fold>
+            = parse_fold_instruction() ** "\n"
+
+        rule parse_fold_instruction() -> Fold
+            = "fold along " fold:parse_fold() {
+                fold
+            }
+
+        rule parse_fold() -> Fold
+            = "y=" value:parse_number() { Fold::Row(value) }
+            / "x=" value:parse_number() { Fold::Column(value) }
+
+        rule parse_location() -> Location
+            = column:parse_number() "," row:parse_number() {
+                Location { row, column }
+            }
+
+        rule parse_number() -> usize
+            = s:$(['0'..='9']+) {?
+                s.parse().or(Err("number"))
+            }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = (Paper, Vec<Fold>);
+    type AnswerA = usize;
+    type AnswerB = Paper;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        transparent_paper_parser::parse(input).map_err(|error| ParseError::from_peg(input, error))
+    }
+
+    fn solve_part_a(input: &Self::Input<'_>) -> Self::AnswerA {
+        let (paper, folds) = input;
+        let paper = paper.clone() + folds[0];
+        paper.dots.len()
+    }
+
+    fn solve_part_b(input: &Self::Input<'_>) -> Self::AnswerB {
+        let (paper, folds) = input;
+        paper.clone() + folds.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_location_add_fold() {
+        assert_eq!(
+            Location { row: 0, column: 0 } + Fold::Row(1),
+            Location { row: 0, column: 0 }
+        );
+        assert_eq!(
+            Location { row: 14, column: 0 } + Fold::Row(7),
+            Location { row: 0, column: 0 }
+        );
+        assert_eq!(
+            Location { row: 0, column: 0 } + Fold::Column(1),
+            Location { row: 0, column: 0 }
+        );
+        assert_eq!(
+            Location { row: 0, column: 10 } + Fold::Column(5),
+            Location { row: 0, column: 0 }
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let input = fs::read_to_string("input-test").unwrap();
+        let input = Day13::parse(&input).unwrap();
+        assert_eq!(Day13::solve_part_a(&input), 17);
+        let actual_output = format!("{}", Day13::solve_part_b(&input));
+        let expected_output = fs::read_to_string("output-test").unwrap();
+        assert_eq!(actual_output, expected_output);
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let generated = solution::generate::<Day13>(seed, 200);
+            let input = Day13::parse(&generated.input).unwrap();
+            assert_eq!(input.0.dots.len(), 200);
+
+            let mut dots: Vec<(usize, usize)> = Day13::solve_part_b(&input)
+                .dots
+                .iter()
+                .map(|location| (location.column, location.row))
+                .collect();
+            dots.sort_unstable();
+            let truth: Vec<(usize, usize)> =
+                serde_json::from_value(generated.truth.unwrap()["dots"].clone()).unwrap();
+            assert_eq!(dots, truth);
+        }
+    }
+
+    #[test]
+    fn test_generate_limit() {
+        // Every place on the sheet that folds into the code, and no more.
+        let generated = solution::generate::<Day13>(0, usize::MAX);
+        let input = Day13::parse(&generated.input).unwrap();
+        assert_eq!(input.0.dots.len(), (40 * 6) << 12);
+        assert_eq!(Day13::solve_part_b(&input).dots.len(), 40 * 6);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day13::parse("6,10\n0,14\n\nfold along z=7\n").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 12));
+        assert_eq!(error.expected, vec!["\"x=\"", "\"y=\""]);
+    }
+}
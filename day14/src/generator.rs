@@ -0,0 +1,33 @@
+use crate::Day14;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use solution::{Generated, Generator};
+
+// The real input uses this many different elements.
+const NUM_ELEMENTS: usize = 10;
+
+/// A polymer template with an insertion rule for every pair of elements, where the size is the
+/// length of the template.
+impl Generator for Day14 {
+    const DEFAULT_SIZE: usize = 20;
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+        let mut alphabet: Vec<char> = ('A'..='Z').collect();
+        alphabet.shuffle(rng);
+
+        let elements = &alphabet[..rng.gen_range(1..=NUM_ELEMENTS)];
+
+        let mut input: String = (0..size.max(1))
+            .map(|_| *elements.choose(rng).unwrap())
+            .collect();
+        input += "\n\n";
+
+        for &a in elements {
+            for &b in elements {
+                input += &format!("{}{} -> {}\n", a, b, elements.choose(rng).unwrap());
+            }
+        }
+
+        Generated::new(input)
+    }
+}
@@ -0,0 +1,323 @@
+mod generator;
+
+use solution::{ParseError, Solution};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+
+type Element = char;
+type ElementPair = [Element; 2];
+
+trait Polymerization {
+    fn step(&mut self);
+
+    fn element_counts(&self) -> HashMap<Element, usize>;
+
+    fn elements_sorted_by_frequency(&self) -> Vec<(Element, usize)> {
+        let mut frequencies: Vec<(Element, usize)> = self.element_counts().into_iter().collect();
+        frequencies.sort_by_key(|&(_, frequency)| std::cmp::Reverse(frequency));
+        frequencies
+    }
+}
+
+// The polymer as a literal chain of elements. Each step roughly doubles its length, so this is
+// only practical for a handful of steps.
+#[derive(Debug, Clone)]
+pub struct PolymerChain {
+    elements: Vec<Element>,
+    insertion_rules: HashMap<ElementPair, Element>,
+}
+
+impl Polymerization for PolymerChain {
+    fn step(&mut self) {
+        let n = self.elements.len();
+        let mut last: Option<char> = None;
+        let mut elements: Vec<Element> = Vec::with_capacity(n * 2 - 1);
+
+        for pair in self.elements.windows(2) {
+            let element = *self.insertion_rules.get(pair).unwrap();
+            elements.push(pair[0]);
+            elements.push(element);
+            last = Some(pair[1])
+        }
+
+        if let Some(element) = last {
+            elements.push(element);
+        }
+
+        self.elements = elements;
+    }
+
+    fn element_counts(&self) -> HashMap<Element, usize> {
+        self.elements
+            .iter()
+            .copied()
+            .fold(HashMap::new(), |mut map, element| {
+                map.entry(element)
+                    .and_modify(|frequency| *frequency += 1)
+                    .or_insert(1);
+                map
+            })
+    }
+}
+
+impl fmt::Display for PolymerChain {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}", self.elements.iter().collect::<String>())?;
+        Ok(())
+    }
+}
+
+// The polymer as counts of its elements and adjacent pairs of elements. The order of the
+// elements is lost, but each step only costs as much as the number of distinct pairs.
+#[derive(Debug, Clone)]
+pub struct Polymer {
+    element_counts: HashMap<Element, usize>,
+    element_pairs: HashMap<ElementPair, usize>,
+    insertion_rules: HashMap<ElementPair, Element>,
+}
+
+impl From<&PolymerChain> for Polymer {
+    fn from(chain: &PolymerChain) -> Self {
+        let element_counts = chain.element_counts();
+        let element_pairs: HashMap<ElementPair, usize> =
+            chain
+                .elements
+                .windows(2)
+                .fold(HashMap::new(), |mut map, pair| {
+                    if let [a, b] = pair {
+                        map.entry([*a, *b])
+                            .and_modify(|count| *count += 1)
+                            .or_insert(1);
+                    }
+                    map
+                });
+
+        Self {
+            element_counts,
+            element_pairs,
+            insertion_rules: chain.insertion_rules.clone(),
+        }
+    }
+}
+
+impl Polymerization for Polymer {
+    fn step(&mut self) {
+        let mut new_pairs: HashMap<ElementPair, usize> = Default::default();
+
+        for (pair, pair_total) in self.element_pairs.iter_mut() {
+            if *pair_total > 0 {
+                let new_element = *self.insertion_rules.get(pair).unwrap();
+
+                new_pairs
+                    .entry([pair[0], new_element])
+                    .and_modify(|new| *new += *pair_total)
+                    .or_insert(*pair_total);
+                new_pairs
+                    .entry([new_element, pair[1]])
+                    .and_modify(|new| *new += *pair_total)
+                    .or_insert(*pair_total);
+
+                self.element_counts
+                    .entry(new_element)
+                    .and_modify(|total| *total += *pair_total)
+                    .or_insert(*pair_total);
+
+                *pair_total = 0;
+            }
+        }
+
+        for (pair, new) in new_pairs.into_iter() {
+            self.element_pairs
+                .entry(pair)
+                .and_modify(|count| *count += new)
+                .or_insert(new);
+        }
+    }
+
+    fn element_counts(&self) -> HashMap<Element, usize> {
+        self.element_counts.clone()
+    }
+}
+
+fn element_frequency_difference_after_n_steps(
+    polymer: &mut impl Polymerization,
+    n: usize,
+) -> usize {
+    for _ in 0..n {
+        polymer.step();
+    }
+
+    let element_frequencies = polymer.elements_sorted_by_frequency();
+    element_frequencies.first().unwrap().1 - element_frequencies.last().unwrap().1
+}
+
+// Finds a pair that the polymer could come to hold but that has no rule, following the pairs in
+// the template through the rules they lead to. It's given with the pair whose rule made it, or
+// nothing if it's in the template.
+fn find_missing_rule(chain: &PolymerChain) -> Option<(ElementPair, Option<ElementPair>)> {
+    let mut seen: HashSet<ElementPair> = Default::default();
+    let mut queue: VecDeque<(ElementPair, Option<ElementPair>)> = chain
+        .elements
+        .windows(2)
+        .map(|pair| ([pair[0], pair[1]], None))
+        .collect();
+
+    while let Some((pair, source)) = queue.pop_front() {
+        if !seen.insert(pair) {
+            continue;
+        }
+
+        match chain.insertion_rules.get(&pair) {
+            Some(&element) => {
+                queue.push_back(([pair[0], element], Some(pair)));
+                queue.push_back(([element, pair[1]], Some(pair)));
+            }
+            None => return Some((pair, source)),
+        }
+    }
+
+    None
+}
+
+peg::parser! {
+    grammar polymer_parser() for str {
+        pub rule parse() -> PolymerChain
+            = elements:parse_template() "\n\n" insertion_rules:parse_insertion_rules() "\n"? {
+                PolymerChain { elements, insertion_rules }
+            }
+
+        rule parse_template() -> Vec<Element>
+            = parse_element()+
+
+        rule parse_insertion_rules() -> HashMap<ElementPair, Element>
+            = items:(parse_insertion_rule() ** "\n") {
+                items.into_iter().collect()
+            }
+
+        rule parse_insertion_rule() -> (ElementPair, Element)
+            = a:parse_element() b:parse_element() " -> " value:parse_element() {
+                ([a, b], value)
+            }
+
+        rule parse_element() -> Element
+            = s:$(['A'..='Z']) {?
+                s.chars().next().ok_or("element")
+            }
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = PolymerChain;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    // A pair with no rule would leave a step with nothing to insert. One from the template is
+    // pointed at there, and one that a rule makes is pointed at by that rule.
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let chain =
+            polymer_parser::parse(input).map_err(|error| ParseError::from_peg(input, error))?;
+
+        if let Some((pair, source)) = find_missing_rule(&chain) {
+            let expected = [format!("a rule for {}{}", pair[0], pair[1])];
+            let error = match source {
+                None => {
+                    let i = chain.elements.windows(2).position(|p| p == pair).unwrap();
+                    ParseError::new(input, i, expected)
+                }
+                Some(source) => {
+                    let prefix = format!("{}{} ->", source[0], source[1]);
+                    let line = input
+                        .lines()
+                        .find(|line| line.starts_with(&prefix))
+                        .unwrap();
+                    ParseError::at(input, line, expected)
+                }
+            };
+            return Err(error);
+        }
+
+        Ok(chain)
+    }
+
+    fn solve_part_a(chain: &Self::Input<'_>) -> Self::AnswerA {
+        element_frequency_difference_after_n_steps(&mut chain.clone(), 10)
+    }
+
+    fn solve_part_b(chain: &Self::Input<'_>) -> Self::AnswerB {
+        element_frequency_difference_after_n_steps(&mut Polymer::from(chain), 40)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_example() {
+        let input = fs::read_to_string("input-test").unwrap();
+        let mut chain = polymer_parser::parse(&input).unwrap();
+        let mut polymer = Polymer::from(&chain);
+
+        chain.step();
+        assert_eq!(format!("{}", chain), "NCNBCHB");
+
+        chain.step();
+        assert_eq!(format!("{}", chain), "NBCCNBBBCBHCB");
+
+        chain.step();
+        assert_eq!(format!("{}", chain), "NBBBCNCCNBBNBNBBCHBHHBCHB");
+
+        chain.step();
+        assert_eq!(
+            format!("{}", chain),
+            "NBBNBNBBCCNBCNCCNBBNBBNBBBNBBNBBCBHCBHHNHCBBCBHCB"
+        );
+
+        // Steps 1-4 done above.
+        assert_eq!(
+            element_frequency_difference_after_n_steps(&mut chain, 6),
+            1588
+        );
+
+        assert_eq!(
+            element_frequency_difference_after_n_steps(&mut polymer, 40),
+            2188189693529
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day14::parse("NNCB\n\nNN -> C\nNC -> B\nCN -> C\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+        assert_eq!(error.expected, vec!["a rule for CB"]);
+
+        // Every pair that a rule makes needs a rule too.
+        let error = Day14::parse("NN\n\nNN -> C\nNC -> N\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.expected, vec!["a rule for CN"]);
+    }
+
+    #[test]
+    fn test_polymer_against_chain() {
+        for seed in 0..50 {
+            let input = solution::generate::<Day14>(seed, 2 + seed as usize % 10).input;
+            let mut chain = Day14::parse(&input).unwrap();
+            let mut polymer = Polymer::from(&chain);
+
+            for steps in 0..=10 {
+                assert_eq!(
+                    polymer.element_counts(),
+                    chain.element_counts(),
+                    "seed {} after {} steps",
+                    seed,
+                    steps
+                );
+                chain.step();
+                polymer.step();
+            }
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{Day15, MAX_RISK_LEVEL};
+use rand::Rng;
+use solution::{Generated, Generator};
+
+/// A square cavern of random risk levels, where the size is the length of its sides.
+impl Generator for Day15 {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+        let size = size.max(1);
+        let mut input = String::with_capacity((size + 1) * size);
+
+        for _ in 0..size {
+            for _ in 0..size {
+                input += &rng.gen_range(1..=MAX_RISK_LEVEL).to_string();
+            }
+            input += "\n";
+        }
+
+        Generated::new(input)
+    }
+}
@@ -0,0 +1,266 @@
+mod generator;
+
+use geometry::Vector;
+use grid::{Grid, Location, Neighborhood};
+use solution::{ParseError, Solution};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::fmt;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct State {
+    cost: usize,
+    location: Location,
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.cmp(&self.cost)
+    }
+}
+
+type RiskLevel = u8;
+const MAX_RISK_LEVEL: u8 = 9;
+
+#[derive(Debug)]
+pub struct Cavern {
+    start: Location,
+    exit: Location,
+    risk_levels: Grid<RiskLevel>,
+}
+
+impl fmt::Display for Cavern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}", self.risk_levels)
+    }
+}
+
+impl Cavern {
+    fn adjacent_locations(&self, location: &Location) -> Vec<Location> {
+        self.risk_levels
+            .adjacent_locations(location, Neighborhood::Four)
+    }
+
+    fn calculate_minimum_risk(&self) -> Option<usize> {
+        let mut frontier = BinaryHeap::new();
+
+        let mut cost_so_far = Grid::new(
+            self.risk_levels.height(),
+            self.risk_levels.width(),
+            usize::MAX,
+        );
+
+        frontier.push(State {
+            location: self.start,
+            cost: 0,
+        });
+
+        cost_so_far[self.start] = 0;
+
+        while let Some(State { location, cost }) = frontier.pop() {
+            if location == self.exit {
+                return Some(cost);
+            }
+
+            if cost > cost_so_far[location] {
+                continue;
+            }
+
+            for adj_location in self.adjacent_locations(&location) {
+                let risk_level = self.risk_levels[adj_location];
+
+                let adj_state = State {
+                    location: adj_location,
+                    cost: cost + risk_level as usize,
+                };
+
+                let adj_cost_so_far = &mut cost_so_far[adj_location];
+
+                if adj_state.cost < *adj_cost_so_far {
+                    frontier.push(adj_state);
+                    *adj_cost_so_far = adj_state.cost;
+                }
+            }
+        }
+
+        None
+    }
+
+    fn tile(&self, size: usize) -> Cavern {
+        let tile_height = self.exit.row + 1;
+        let tile_width = self.exit.column + 1;
+
+        let height = tile_height * size;
+        let width = tile_width * size;
+
+        let start = self.start;
+        let exit = Location {
+            row: height - 1,
+            column: width - 1,
+        };
+
+        // Using the tiling rules from the puzzle page, a pattern can be seen where identical tiles
+        // lie on diagonals, so we can calculate the first tile and then insert it along the
+        // locations that lie on the diagonal.
+
+        // 0 1 2 3 4
+        // 1 2 3 4 5
+        // 2 3 4 5 6
+        // 3 4 5 6 7
+        // 4 5 6 7 8
+
+        // This is the number of diagonal line that need to be filled. This can also be thought of
+        // as the number of unique tiles.
+        let num_diags = size * 2 - 2;
+
+        // I chose to start the diagonal at the top/right of the grid and apply it going down and to
+        // the left. This is just an increment that can be added to a location to get it's adjacent
+        // diagonal position.
+        let diag_step = Vector::<2>::new(-(tile_width as isize), tile_height as isize);
+
+        let mut risk_levels = Grid::new(height, width, 0);
+
+        for (location, &risk_level) in self.risk_levels.iter() {
+            // Insert the value for the first tile (the original location from part 1).
+            risk_levels[location] = risk_level;
+
+            // Calculate the tile for each diagonal line.
+            for diag in 1..=num_diags {
+                // Keep the risk level between 1 and MAX_RISK_LEVEL, wrapping.
+                let diag_risk_level = (risk_level - 1 + diag as RiskLevel) % MAX_RISK_LEVEL + 1;
+
+                // The number of tiles that lie on this diagonal line.
+                let num_diag_steps = if diag < size { diag } else { num_diags - diag } + 1;
+
+                // The starting tile will be along the top for the first half, and along the right
+                // for the rest.
+                let start_tile_location = if diag < size {
+                    Location {
+                        row: location.row,
+                        column: diag * tile_width + location.column,
+                    }
+                } else {
+                    Location {
+                        row: (diag - size + 1) * tile_height + location.row,
+                        // The same column as the last diagonal along the top.
+                        column: (size - 1) * tile_width + location.column,
+                    }
+                };
+
+                for i in 0..num_diag_steps {
+                    let step = diag_step * i as isize;
+                    let tile_location = start_tile_location + step;
+                    risk_levels[tile_location] = diag_risk_level;
+                }
+            }
+        }
+
+        Cavern {
+            start,
+            exit,
+            risk_levels,
+        }
+    }
+}
+
+fn parse_cavern(s: &str) -> Result<Cavern, ParseError> {
+    let risk_levels = Grid::parse_digits(s)?;
+    let start = Location { row: 0, column: 0 };
+    let exit = Location {
+        row: risk_levels.height() - 1,
+        column: risk_levels.width() - 1,
+    };
+    Ok(Cavern {
+        start,
+        exit,
+        risk_levels,
+    })
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = Cavern;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_cavern(input)
+    }
+
+    fn solve_part_a(cavern: &Self::Input<'_>) -> Self::AnswerA {
+        cavern.calculate_minimum_risk().unwrap()
+    }
+
+    fn solve_part_b(cavern: &Self::Input<'_>) -> Self::AnswerB {
+        cavern.tile(5).calculate_minimum_risk().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    // Fills in each tile in turn, straight from the rules on the puzzle page.
+    fn tile_by_looping(cavern: &Cavern, size: usize) -> Grid<RiskLevel> {
+        let tile_height = cavern.risk_levels.height();
+        let tile_width = cavern.risk_levels.width();
+        let mut risk_levels = Grid::new(tile_height * size, tile_width * size, 0);
+
+        for tile_row in 0..size {
+            for tile_column in 0..size {
+                let increase = (tile_row + tile_column) as RiskLevel;
+
+                for (location, &risk_level) in cavern.risk_levels.iter() {
+                    let tile_location = Location {
+                        row: tile_row * tile_height + location.row,
+                        column: tile_column * tile_width + location.column,
+                    };
+                    risk_levels[tile_location] = (risk_level - 1 + increase) % MAX_RISK_LEVEL + 1;
+                }
+            }
+        }
+
+        risk_levels
+    }
+
+    #[test]
+    fn test_example() {
+        let input = fs::read_to_string("input-test").unwrap();
+        let cavern = parse_cavern(&input).unwrap();
+        assert_eq!(cavern.calculate_minimum_risk().unwrap(), 40);
+
+        let cavern = cavern.tile(5);
+        let input_full = fs::read_to_string("input-test-full").unwrap();
+        assert_eq!(format!("{}", &cavern), input_full);
+        assert_eq!(cavern.calculate_minimum_risk().unwrap(), 315);
+    }
+
+    #[test]
+    fn test_tile_against_looping() {
+        for seed in 0..50 {
+            let input = solution::generate::<Day15>(seed, 1 + seed as usize % 12).input;
+            let cavern = parse_cavern(&input).unwrap();
+
+            for size in 1..=6 {
+                let tiled = cavern.tile(size);
+                assert_eq!(
+                    tiled.risk_levels,
+                    tile_by_looping(&cavern, size),
+                    "seed {} tiled {} times",
+                    seed,
+                    size
+                );
+                assert_eq!(tiled.start, cavern.start);
+                assert_eq!(tiled.exit, tiled.risk_levels.locations().last().unwrap());
+            }
+        }
+    }
+}
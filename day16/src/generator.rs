@@ -0,0 +1,147 @@
+use crate::{Day16, CHUNK_SIZE, GROUP_SIZE, HEX_DIGIT_SIZE};
+use rand::Rng;
+use serde_json::json;
+use solution::{Generated, Generator};
+
+const LITERAL: usize = 4;
+const MAX_SUBPACKETS: usize = 5;
+const LENGTH_SIZE: usize = 15;
+const COUNT_SIZE: usize = 11;
+
+#[derive(Debug, Default)]
+struct Packet {
+    bits: Vec<bool>,
+    version_sum: usize,
+    value: usize,
+}
+
+fn push_bits(bits: &mut Vec<bool>, data: usize, count: usize) {
+    bits.extend((0..count).rev().map(|i| (data >> i) & 1 == 1));
+}
+
+fn literal<R: Rng + ?Sized>(rng: &mut R, version: usize) -> Packet {
+    let value = rng.gen_range(0..1 << 12);
+    let mut packet = Packet {
+        version_sum: version,
+        value,
+        ..Default::default()
+    };
+
+    push_bits(&mut packet.bits, version, 3);
+    push_bits(&mut packet.bits, LITERAL, 3);
+
+    let groups = (usize::BITS - value.leading_zeros()).max(1) as usize;
+    let groups = groups.div_ceil(HEX_DIGIT_SIZE);
+    for i in (0..groups).rev() {
+        let more = if i > 0 { 1 << HEX_DIGIT_SIZE } else { 0 };
+        let group = (value >> (i * HEX_DIGIT_SIZE)) & ((1 << HEX_DIGIT_SIZE) - 1);
+        push_bits(&mut packet.bits, more | group, GROUP_SIZE);
+    }
+
+    packet
+}
+
+// Builds a packet made of `size` packets in total, counting itself and all of its subpackets.
+fn packet<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Packet {
+    let version = rng.gen_range(0..8);
+
+    if size == 1 {
+        return literal(rng, version);
+    }
+
+    // The comparisons need exactly two subpackets, so they're only possible with enough room.
+    let mut type_id = if size >= 3 && rng.gen_ratio(3, 7) {
+        rng.gen_range(5..=7)
+    } else {
+        rng.gen_range(0..4)
+    };
+
+    let count = match type_id {
+        5..=7 => 2,
+        _ => rng.gen_range(1..=MAX_SUBPACKETS.min(size - 1)),
+    };
+
+    // Give each subpacket one packet, and then share out the rest at random.
+    let mut sizes = vec![1; count];
+    for _ in 0..size - 1 - count {
+        sizes[rng.gen_range(0..count)] += 1;
+    }
+
+    let subpackets: Vec<Packet> = sizes.into_iter().map(|size| packet(rng, size)).collect();
+    let values: Vec<usize> = subpackets.iter().map(|packet| packet.value).collect();
+
+    // Products can get too big to be decoded, so those are summed instead.
+    let product = values
+        .iter()
+        .try_fold(1usize, |product, &value| product.checked_mul(value));
+    if type_id == 1 && product.is_none() {
+        type_id = 0;
+    }
+
+    let value = match type_id {
+        0 => values.iter().sum(),
+        1 => product.unwrap(),
+        2 => *values.iter().min().unwrap(),
+        3 => *values.iter().max().unwrap(),
+        5 => (values[0] > values[1]) as usize,
+        6 => (values[0] < values[1]) as usize,
+        7 => (values[0] == values[1]) as usize,
+        _ => unreachable!(),
+    };
+
+    let mut packet = Packet {
+        version_sum: version + subpackets.iter().map(|p| p.version_sum).sum::<usize>(),
+        value,
+        ..Default::default()
+    };
+
+    push_bits(&mut packet.bits, version, 3);
+    push_bits(&mut packet.bits, type_id, 3);
+
+    let length: usize = subpackets.iter().map(|p| p.bits.len()).sum();
+    if length < 1 << LENGTH_SIZE && rng.gen() {
+        push_bits(&mut packet.bits, 0, 1);
+        push_bits(&mut packet.bits, length, LENGTH_SIZE);
+    } else {
+        push_bits(&mut packet.bits, 1, 1);
+        push_bits(&mut packet.bits, count, COUNT_SIZE);
+    }
+
+    for subpacket in subpackets {
+        packet.bits.extend(subpacket.bits);
+    }
+
+    packet
+}
+
+/// A transmission made of one outermost packet, where the size is the total number of packets.
+/// The truth is the version sum and the value of the outermost packet.
+impl Generator for Day16 {
+    const DEFAULT_SIZE: usize = 60;
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+        let mut packet = packet(rng, size.max(1));
+
+        // The transmission is padded with zeros to a whole number of chunks.
+        let padding = packet.bits.len().next_multiple_of(CHUNK_SIZE) - packet.bits.len();
+        packet.bits.extend(vec![false; padding]);
+
+        let mut input: String = packet
+            .bits
+            .chunks(HEX_DIGIT_SIZE)
+            .map(|digit| {
+                let digit = digit.iter().fold(0, |digit, &bit| digit << 1 | bit as u32);
+                char::from_digit(digit, 16).unwrap().to_ascii_uppercase()
+            })
+            .collect();
+        input += "\n";
+
+        Generated {
+            input,
+            truth: Some(json!({
+                "version_sum": packet.version_sum,
+                "value": packet.value,
+            })),
+        }
+    }
+}
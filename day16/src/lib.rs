@@ -0,0 +1,353 @@
+mod generator;
+
+use solution::{ParseError, Solution};
+
+// The most efficient storage of a 2-digit hexadecimal number.
+type Chunk = u8;
+
+// The number of bits in each chunk.
+const CHUNK_SIZE: usize = std::mem::size_of::<Chunk>() * 8;
+
+// The number of bits in each hexadecimal digit.
+const HEX_DIGIT_SIZE: usize = CHUNK_SIZE / 2;
+
+// The number of bits in a literal packet value group.
+const GROUP_SIZE: usize = HEX_DIGIT_SIZE + 1;
+
+#[derive(Debug, Clone)]
+pub struct BitReader {
+    size: usize,
+    cursor: usize,
+    chunks: Vec<Chunk>,
+}
+
+fn get_bits(data: usize, count: usize, offset: usize) -> usize {
+    (data & ((1 << count) - 1) << offset) >> offset
+}
+
+impl BitReader {
+    fn decode_hex(s: &str) -> Result<Self, ParseError> {
+        // Every chunk is made of two digits, so an odd digit out is reported as the end of the
+        // input being too early.
+        let chunks = (0..s.len())
+            .step_by(2)
+            .map(|i| {
+                s.get(i..i + 2)
+                    .and_then(|digits| Chunk::from_str_radix(digits, 16).ok())
+                    .ok_or_else(|| ParseError::new(s, i, ["hexadecimal digit pair"]))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            cursor: 0,
+            size: s.len() * 4,
+            chunks,
+        })
+    }
+
+    fn read(&mut self, count: usize) -> Option<usize> {
+        if self.cursor + count > self.size {
+            return None;
+        }
+
+        let start = self.cursor / CHUNK_SIZE;
+        let cursor_end = self.cursor + count - 1;
+        let end = cursor_end / CHUNK_SIZE;
+        let reach = cursor_end % CHUNK_SIZE + 1;
+
+        let mut data: usize = 0;
+        for i in start..=end {
+            data <<= CHUNK_SIZE;
+            data += self.chunks[i] as usize;
+        }
+
+        self.cursor += count;
+
+        let offset = CHUNK_SIZE - reach;
+        Some(get_bits(data, count, offset))
+    }
+
+    // Reads the next `count` bits of a packet that needs them.
+    fn expect(&mut self, count: usize) -> Result<usize, PacketError> {
+        let cursor = self.cursor;
+        self.read(count).ok_or(PacketError {
+            cursor,
+            expected: "more of the packet",
+        })
+    }
+}
+
+/// A packet that can't be decoded, with the bit where it went wrong and what was expected there.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct PacketError {
+    cursor: usize,
+    expected: &'static str,
+}
+
+impl PacketError {
+    fn at(cursor: usize, expected: &'static str) -> Self {
+        Self { cursor, expected }
+    }
+}
+
+fn process_literal(bits: &mut BitReader) -> Result<usize, PacketError> {
+    let cursor = bits.cursor;
+    let mut value: usize = 0;
+
+    loop {
+        let group = bits.expect(GROUP_SIZE)?;
+
+        value = value
+            .checked_mul(1 << HEX_DIGIT_SIZE)
+            .map(|value| value + get_bits(group, HEX_DIGIT_SIZE, 0))
+            .ok_or(PacketError::at(
+                cursor,
+                "a literal value small enough to hold",
+            ))?;
+
+        if get_bits(group, 1, HEX_DIGIT_SIZE) == 0 {
+            break;
+        }
+    }
+
+    Ok(value)
+}
+
+fn process_n_bits_as_packets(
+    bits: &mut BitReader,
+    n: usize,
+) -> Result<Vec<(usize, usize)>, PacketError> {
+    let mut values = Vec::new();
+    let mut n = n;
+
+    while n != 0 {
+        let cursor_before = bits.cursor;
+        values.push(process_packet(bits)?);
+        n = n
+            .checked_sub(bits.cursor - cursor_before)
+            .ok_or(PacketError::at(
+                cursor_before,
+                "a subpacket that ends within its parent's length",
+            ))?;
+    }
+
+    Ok(values)
+}
+
+fn process_next_n_packets(
+    bits: &mut BitReader,
+    n: usize,
+) -> Result<Vec<(usize, usize)>, PacketError> {
+    (0..n).map(|_| process_packet(bits)).collect()
+}
+
+fn process_packet(bits: &mut BitReader) -> Result<(usize, usize), PacketError> {
+    let cursor = bits.cursor;
+    let version = bits.expect(3)?;
+    let type_id = bits.expect(3)?;
+
+    if type_id == 4 {
+        let value = process_literal(bits)?;
+        return Ok((version, value));
+    }
+
+    let length_type_id = bits.expect(1)?;
+
+    let version_sums_and_values = if length_type_id == 0 {
+        let n = bits.expect(15)?;
+        process_n_bits_as_packets(bits, n)?
+    } else {
+        let n = bits.expect(11)?;
+        process_next_n_packets(bits, n)?
+    };
+
+    let version_sum: usize = version
+        + version_sums_and_values
+            .iter()
+            .map(|(version_sum, _)| *version_sum)
+            .sum::<usize>();
+
+    let mut values = version_sums_and_values.iter().map(|(_, value)| *value);
+    let too_big = PacketError::at(cursor, "a value small enough to hold");
+
+    let value: usize = match type_id {
+        0 => values
+            .try_fold(0usize, |a, b| a.checked_add(b))
+            .ok_or(too_big)?,
+        1 => values
+            .try_fold(1usize, |a, b| a.checked_mul(b))
+            .ok_or(too_big)?,
+        2 => values.min().ok_or(PacketError::at(
+            cursor,
+            "a subpacket to take the minimum of",
+        ))?,
+        3 => values.max().ok_or(PacketError::at(
+            cursor,
+            "a subpacket to take the maximum of",
+        ))?,
+        5..=7 => {
+            let (a, b) = match version_sums_and_values[..] {
+                [(_, a), (_, b)] => (a, b),
+                _ => return Err(PacketError::at(cursor, "exactly two subpackets to compare")),
+            };
+            let result = match type_id {
+                5 => a > b,
+                6 => a < b,
+                7 => a == b,
+                _ => unreachable!(),
+            };
+            result as usize
+        }
+        _ => unreachable!(),
+    };
+
+    Ok((version_sum, value))
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = BitReader;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    // The packet is decoded here to check it, since the answers can't be errors. A bad packet is
+    // pointed at by the hexadecimal digit holding the bit where it went wrong.
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let bits = BitReader::decode_hex(input.trim_end())?;
+
+        process_packet(&mut bits.clone()).map_err(|error| {
+            ParseError::new(input, error.cursor / HEX_DIGIT_SIZE, [error.expected])
+        })?;
+
+        Ok(bits)
+    }
+
+    fn solve_part_a(bits: &Self::Input<'_>) -> Self::AnswerA {
+        process_packet(&mut bits.clone())
+            .expect("packets are decoded while they're parsed")
+            .0
+    }
+
+    fn solve_part_b(bits: &Self::Input<'_>) -> Self::AnswerB {
+        process_packet(&mut bits.clone())
+            .expect("packets are decoded while they're parsed")
+            .1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bit_reader() {
+        let mut bits = BitReader::decode_hex("D2FE28").unwrap();
+        assert_eq!(bits.read(3), Some(0b110));
+        assert_eq!(bits.read(3), Some(0b100));
+        assert_eq!(bits.read(5), Some(0b10111));
+        assert_eq!(bits.read(5), Some(0b11110));
+        assert_eq!(bits.read(5), Some(0b00101));
+        assert_eq!(bits.read(3), Some(0));
+        assert_eq!(bits.read(1), None);
+    }
+
+    #[test]
+    fn test_process_literal_packet() {
+        let mut bits = BitReader::decode_hex("D2FE28").unwrap();
+        assert_eq!(process_packet(&mut bits), Ok((6, 2021)));
+    }
+
+    #[test]
+    fn test_process_operator_packet_sum() {
+        let mut bits = BitReader::decode_hex("C200B40A82").unwrap();
+        assert_eq!(process_packet(&mut bits), Ok((14, 3)));
+    }
+
+    #[test]
+    fn test_process_operator_packet_product() {
+        let mut bits = BitReader::decode_hex("04005AC33890").unwrap();
+        assert_eq!(process_packet(&mut bits), Ok((8, 54)));
+    }
+
+    #[test]
+    fn test_process_operator_packet_minimum() {
+        let mut bits = BitReader::decode_hex("880086C3E88112").unwrap();
+        assert_eq!(process_packet(&mut bits), Ok((15, 7)));
+    }
+
+    #[test]
+    fn test_process_operator_packet_maximum() {
+        let mut bits = BitReader::decode_hex("CE00C43D881120").unwrap();
+        assert_eq!(process_packet(&mut bits), Ok((11, 9)));
+    }
+
+    #[test]
+    fn test_process_operator_packet_less_than() {
+        let mut bits = BitReader::decode_hex("D8005AC2A8F0").unwrap();
+        assert_eq!(process_packet(&mut bits), Ok((13, 1)));
+    }
+
+    #[test]
+    fn test_process_operator_packet_greater_than() {
+        let mut bits = BitReader::decode_hex("F600BC2D8F").unwrap();
+        assert_eq!(process_packet(&mut bits), Ok((19, 0)));
+    }
+
+    #[test]
+    fn test_process_operator_packet_equal_to() {
+        let mut bits = BitReader::decode_hex("9C005AC2F8F0").unwrap();
+        assert_eq!(process_packet(&mut bits), Ok((16, 0)));
+    }
+
+    #[test]
+    fn test_process_operator_packet_sum_equal_to_product() {
+        let mut bits = BitReader::decode_hex("9C0141080250320F1802104A08").unwrap();
+        assert_eq!(process_packet(&mut bits), Ok((20, 1)));
+    }
+
+    fn assert_packet_version_sum(s: &str, expected_sum: usize) {
+        let mut bits = BitReader::decode_hex(s).unwrap();
+        assert_eq!(process_packet(&mut bits).unwrap().0, expected_sum);
+    }
+
+    #[test]
+    fn test_examples() {
+        assert_packet_version_sum("8A004A801A8002F478", 16);
+        assert_packet_version_sum("620080001611562C8802118E34", 12);
+        assert_packet_version_sum("C0015000016115A2E0802F182340", 23);
+        assert_packet_version_sum("A0016C880162017C3686B18A3D4780", 31);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        // An operator packet whose length is cut off.
+        let error = Day16::parse("00\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 2));
+        assert_eq!(error.expected, vec!["more of the packet"]);
+
+        // A greater than packet with only one literal in it.
+        let error = Day16::parse("D6004408").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.expected, vec!["exactly two subpackets to compare"]);
+
+        // A literal packet with seventeen groups of four bits.
+        let error = Day16::parse("D3FFFFFFFFFFFFFFFFFFFDE0").unwrap_err();
+        assert_eq!(error.expected, vec!["a literal value small enough to hold"]);
+
+        // Sixteen groups still fit.
+        let bits = Day16::parse("D3FFFFFFFFFFFFFFFFFFBC").unwrap();
+        assert_eq!(Day16::solve_part_b(&bits), usize::MAX);
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..20 {
+            let generated = solution::generate::<Day16>(seed, 30);
+            let bits = Day16::parse(&generated.input).unwrap();
+            let truth = generated.truth.unwrap();
+            assert_eq!(Day16::solve_part_a(&bits), truth["version_sum"]);
+            assert_eq!(Day16::solve_part_b(&bits), truth["value"]);
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::Day17;
+use rand::Rng;
+use solution::{Generated, Generator};
+
+/// A target below and ahead of the launcher, like the real input's, where the size is how far
+/// ahead its far edge can be, from 60 up. It's about a quarter as far down. The target always spans
+/// a point where the probe runs out of speed ahead, so it can be hit by dropping straight down into
+/// it.
+impl Generator for Day17 {
+    const DEFAULT_SIZE: usize = 300;
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+        let size = size.max(60) as isize;
+
+        let width = rng.gen_range(5..=size / 10);
+        // The probe stops after going the triangular number of its starting speed ahead.
+        let stops: Vec<isize> = (1..)
+            .map(|speed| speed * (speed + 1) / 2)
+            .take_while(|stop| stop + width <= size)
+            .collect();
+        let stop = stops[rng.gen_range(stops.len() / 2..stops.len())];
+        let left = (stop - rng.gen_range(0..=width)).max(1);
+
+        let height = rng.gen_range(3..=size / 12);
+        let bottom = -rng.gen_range(height + 1..=size / 4);
+
+        Generated::new(format!(
+            "target area: x={}..{}, y={}..{}\n",
+            left,
+            left + width,
+            bottom,
+            bottom + height
+        ))
+    }
+}
@@ -1,88 +1,13 @@
-use std::fmt;
-use std::fs;
-use std::ops::AddAssign;
-
-#[derive(Debug, Default, Clone, Copy, PartialEq)]
-struct Vec2 {
-    x: isize,
-    y: isize,
-}
-
-impl AddAssign<Self> for Vec2 {
-    fn add_assign(&mut self, other: Self) {
-        self.x += other.x;
-        self.y += other.y;
-    }
-}
-
-struct CellIter {
-    extents: Rectangle,
-    current: Vec2,
-}
-
-impl CellIter {
-    fn new(extents: Rectangle) -> Self {
-        Self {
-            extents,
-            current: extents.top_left,
-        }
-    }
-}
-
-impl Iterator for CellIter {
-    type Item = Vec2;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.current.y < self.extents.bottom() {
-            return None;
-        }
-
-        let current = self.current;
-
-        self.current.x += 1;
-        if self.current.x > self.extents.right() {
-            self.current.x = 0;
-            self.current.y -= 1;
-        }
+mod generator;
 
-        Some(current)
-    }
-}
-
-#[derive(Debug, Default, Clone, Copy)]
-pub struct Rectangle {
-    top_left: Vec2,
-    bottom_right: Vec2,
-}
-
-impl Rectangle {
-    fn top(&self) -> isize {
-        self.top_left.y
-    }
-
-    fn bottom(&self) -> isize {
-        self.bottom_right.y
-    }
-
-    fn left(&self) -> isize {
-        self.top_left.x
-    }
+use geometry::{BoundingBox, Vector};
+use solution::{ParseError, Solution};
+use std::fmt;
 
-    fn right(&self) -> isize {
-        self.bottom_right.x
-    }
+type Vec2 = Vector<2>;
 
-    fn contains(&self, point: &Vec2) -> bool {
-        self.top() >= point.y
-            && point.y >= self.bottom()
-            && self.left() <= point.x
-            && point.x <= self.right()
-    }
-
-    fn locations(&self) -> CellIter {
-        CellIter::new(*self)
-    }
-}
+/// The y axis points up, so the top of a rectangle is its maximum y value.
+pub type Rectangle = BoundingBox<2>;
 
 #[derive(Debug)]
 struct Probe {
@@ -101,13 +26,8 @@ impl Probe {
     fn step(&mut self) {
         self.position += self.velocity;
 
-        self.velocity.x += match self.velocity.x {
-            v if v < 0 => 1,
-            v if v > 0 => -1,
-            _ => 0,
-        };
-
-        self.velocity.y -= 1;
+        // Drag pulls the x velocity towards zero, and gravity pulls the y velocity down.
+        self.velocity += Vec2::new(-self.velocity.x().signum(), -1);
     }
 }
 
@@ -136,25 +56,11 @@ impl LaunchSimulation {
 
 impl LaunchSimulation {
     fn extents(&self) -> Rectangle {
-        let mut extents: Rectangle = Default::default();
-
-        let mut points = self.trajectory.clone();
-        points.push(self.target.top_left);
-        points.push(self.target.bottom_right);
+        let origin: Vec2 = Default::default();
+        let mut extents = self.target.union(&Rectangle::new(origin, origin));
 
-        for point in points {
-            if point.x < extents.top_left.x {
-                extents.top_left.x = point.x;
-            }
-            if point.x > extents.bottom_right.x {
-                extents.bottom_right.x = point.x;
-            }
-            if point.y > extents.top_left.y {
-                extents.top_left.y = point.y;
-            }
-            if point.y < extents.bottom_right.y {
-                extents.bottom_right.y = point.y;
-            }
+        for &point in self.trajectory.iter() {
+            extents.extend(point);
         }
 
         extents
@@ -165,7 +71,8 @@ impl LaunchSimulation {
     }
 
     fn past_target(&self) -> bool {
-        self.probe.position.x > self.target.right() || self.probe.position.y < self.target.bottom()
+        self.probe.position.x() > self.target.max.x()
+            || self.probe.position.y() < self.target.min.y()
     }
 }
 
@@ -174,22 +81,24 @@ impl fmt::Display for LaunchSimulation {
         let extents = self.extents();
         let origin: Vec2 = Default::default();
 
-        for location in extents.locations() {
-            let c = if location == origin {
-                'S'
-            } else if self.trajectory.contains(&location) {
-                '#'
-            } else if self.target.contains(&location) {
-                'T'
-            } else {
-                '.'
-            };
-
-            write!(f, "{}", c)?;
-
-            if location.x == extents.right() {
-                writeln!(f)?;
+        for y in (extents.min.y()..=extents.max.y()).rev() {
+            for x in extents.min.x()..=extents.max.x() {
+                let location = Vec2::new(x, y);
+
+                let c = if location == origin {
+                    'S'
+                } else if self.trajectory.contains(&location) {
+                    '#'
+                } else if self.target.contains(&location) {
+                    'T'
+                } else {
+                    '.'
+                };
+
+                write!(f, "{}", c)?;
             }
+
+            writeln!(f)?;
         }
 
         Ok(())
@@ -200,15 +109,7 @@ peg::parser! {
     grammar target_parser() for str {
         pub rule parse() -> Rectangle
             = "target area: x=" xs:parse_range() ", y=" ys:parse_range() "\n"? {
-                let [min_x, max_x] = xs;
-
-                // Not sure why the max y value is first, but it seems consistent.
-                let [max_y, min_y] = ys;
-
-                let top_left = Vec2 { x: min_x, y: min_y };
-                let bottom_right = Vec2 { x: max_x, y: max_y };
-
-                Rectangle { top_left, bottom_right }
+                Rectangle::new(Vec2::new(xs[0], ys[0]), Vec2::new(xs[1], ys[1]))
             }
 
         rule parse_range() -> [isize; 2]
@@ -217,8 +118,8 @@ peg::parser! {
             }
 
         rule parse_number() -> isize
-            = s:$("-"? ['0'..='9']+) {
-                s.parse().unwrap()
+            = s:$("-"? ['0'..='9']+) {?
+                s.parse().or(Err("number"))
             }
     }
 }
@@ -229,24 +130,11 @@ fn get_highest_successful_launch(target: &Rectangle) -> isize {
     // no x velocity that could possibly hit the target, because with any choice, the probe would
     // overshoot it. This makes an appropriate lower bound for a brute force solution.
 
-    let velocity = Vec2 {
-        x: target.left(),
-        y: target.bottom(),
-    };
-    let launch = LaunchSimulation::new(*target, velocity);
-    dbg!(velocity);
-    println!("{}", &launch);
-
     // If you test enough launches, starting at the lower bound above, and increasing steadily,
     // you'll see that there is a point where any more y velocity causes the probe to overshoot the
     // target as it's sinking. This is also happens to be the highest the probe can be launched,
     // therefore it's the solution.
 
-    let velocity = Vec2 { x: 6, y: 9 };
-    let launch = LaunchSimulation::new(*target, velocity);
-    dbg!(velocity);
-    println!("{}", &launch);
-
     // After plotting many launches, a few facts become enter the realm of intuition, and, looking
     // at the physics rules, becomes obvious and concrete:
     //
@@ -275,24 +163,92 @@ fn get_highest_successful_launch(target: &Rectangle) -> isize {
     // distance by how far the bottom of the target is from y=0, you'll get the highest point. This
     // is the same value that you would get if you get the triangular number of `n-1` instead.
 
-    let n = target.bottom().abs() - 1;
+    let n = target.min.y().abs() - 1;
     n * (n + 1) / 2
 }
 
-fn main() {
-    let input = fs::read_to_string("input").unwrap();
-    let target = target_parser::parse(&input).unwrap();
-    dbg!(get_highest_successful_launch(&target));
+fn get_successful_launches(target: &Rectangle) -> Vec<Vec2> {
+    let mut velocities: Vec<Vec2> = Default::default();
+
+    // Building on what we learned from part 1, we just need to do a brute force search with a
+    // little bit of optimization on the bounds. We already know the bounds for the y velocity.
+
+    let target_right = target.max.x();
+    let target_bottom = target.min.y();
+
+    for y in target_bottom..target_bottom.abs() {
+        // We'll be generous with the x values and start with a minimum of 0, and since any value
+        // greater than whatever the right side of the target is will overshoot, we know that's the
+        // best upper bound.
+        for x in 0..=target_right {
+            let velocity = Vec2::new(x, y);
+            let launch = LaunchSimulation::new(*target, velocity);
+
+            // If, at any point, the probe connected with the target, count it.
+            if launch.trajectory.iter().any(|p| target.contains(p)) {
+                velocities.push(velocity);
+            }
+        }
+    }
+
+    velocities
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = Rectangle;
+    type AnswerA = isize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        target_parser::parse(input).map_err(|error| ParseError::from_peg(input, error))
+    }
+
+    fn solve_part_a(target: &Self::Input<'_>) -> Self::AnswerA {
+        get_highest_successful_launch(target)
+    }
+
+    fn solve_part_b(target: &Self::Input<'_>) -> Self::AnswerB {
+        get_successful_launches(target).len()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs;
 
     #[test]
     fn test_example() {
         let input = fs::read_to_string("input-test").unwrap();
         let target = target_parser::parse(&input).unwrap();
         assert_eq!(get_highest_successful_launch(&target), 45);
+        assert_eq!(get_successful_launches(&target).len(), 112);
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let generated = solution::generate::<Day17>(seed, 100 + seed as usize * 20);
+            let target = Day17::parse(&generated.input).unwrap();
+            assert!(target.min.x() > 0 && target.max.y() < 0);
+
+            // The highest launch is worked out without trying any, so check it against the ones
+            // that were tried.
+            let launches = get_successful_launches(&target);
+            let highest = launches
+                .iter()
+                .map(|velocity| velocity.y() * (velocity.y() + 1) / 2)
+                .max()
+                .unwrap();
+            assert_eq!(Day17::solve_part_a(&target), highest);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day17::parse("target area: x=20..30, y=-10..\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 31));
     }
 }
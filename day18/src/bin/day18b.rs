@@ -0,0 +1,15 @@
+use day18::Day18;
+use solution::Solution;
+use std::fs;
+use std::process;
+
+fn main() {
+    let input = fs::read_to_string("input").unwrap();
+    match Day18::parse(&input) {
+        Ok(parsed) => println!("{}", Day18::solve_part_b(&parsed)),
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{Day18, TreeNode, EXPLODE_DEPTH};
+use rand::Rng;
+use solution::{Generated, Generator};
+
+// Pairs deeper than this would explode, so a reduced number never has any.
+const MAX_DEPTH: usize = EXPLODE_DEPTH - 1;
+
+fn random_tree<R: Rng + ?Sized>(rng: &mut R, depth: usize) -> TreeNode {
+    // The root is always a pair, and pairs get less likely further down.
+    if depth == 0 || (depth <= MAX_DEPTH && rng.gen_bool(0.75 - 0.15 * depth as f64)) {
+        TreeNode::Branch(
+            Box::new(random_tree(rng, depth + 1)),
+            Box::new(random_tree(rng, depth + 1)),
+        )
+    } else {
+        TreeNode::Leaf(rng.gen_range(0..10))
+    }
+}
+
+/// Snailfish numbers that are already reduced, like the real input's, where the size is how many
+/// of them there are, from two up.
+impl Generator for Day18 {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+        let input: String = (0..size.max(2))
+            .map(|_| random_tree(rng, 0).encode().unwrap() + "\n")
+            .collect();
+        Generated::new(input)
+    }
+}
@@ -1,5 +1,8 @@
+mod generator;
+
+use itertools::Itertools;
 use serde::{Deserialize, Serialize};
-use std::fs;
+use solution::{ParseError, Solution};
 use std::ops::Add;
 use std::str::FromStr;
 
@@ -7,7 +10,7 @@ const EXPLODE_DEPTH: usize = 4;
 
 type Value = u8;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum TreeNode {
     Leaf(Value),
@@ -15,11 +18,11 @@ pub enum TreeNode {
 }
 
 impl TreeNode {
-    fn decode(s: &str) -> anyhow::Result<Self> {
+    pub fn decode(s: &str) -> anyhow::Result<Self> {
         Ok(serde_json::from_str(s)?)
     }
 
-    fn encode(&self) -> anyhow::Result<String> {
+    pub fn encode(&self) -> anyhow::Result<String> {
         Ok(serde_json::to_string(self)?)
     }
 
@@ -138,22 +141,77 @@ fn sum_trees(roots: Vec<TreeNode>) -> TreeNode {
     roots.into_iter().reduce(|prev, cur| prev + cur).unwrap()
 }
 
-fn parse_trees(s: &str) -> Vec<TreeNode> {
+fn find_largest_magnitude_sum(roots: &[TreeNode], n: usize) -> usize {
+    roots
+        .iter()
+        .permutations(n)
+        .map(|pair| (pair[0].clone() + pair[1].clone()).magnitude())
+        .max()
+        .unwrap()
+}
+
+fn parse_trees(s: &str) -> Result<Vec<TreeNode>, ParseError> {
     s.lines()
-        .map(|line| TreeNode::decode(line).unwrap())
+        .map(|line| {
+            // Reduced numbers only ever hold single digits, and anything bigger could overflow
+            // when a pair explodes into it.
+            if let Some(i) = line
+                .as_bytes()
+                .windows(2)
+                .position(|pair| pair.iter().all(u8::is_ascii_digit))
+            {
+                return Err(ParseError::at(
+                    s,
+                    &line[i..],
+                    ["regular number from 0 to 9"],
+                ));
+            }
+
+            serde_json::from_str(line).map_err(|error| {
+                // The error's column is the last character that was read, counting from 1.
+                let i = error.column().saturating_sub(1).min(line.len());
+                let rest = line.get(i..).unwrap_or(line);
+                ParseError::at(s, rest, ["snailfish number"])
+            })
+        })
         .collect()
 }
 
-fn main() {
-    let s = fs::read_to_string("input").unwrap();
-    let root = sum_trees(parse_trees(&s));
-    dbg!(root.encode().unwrap());
-    dbg!(root.magnitude());
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input<'a> = Vec<TreeNode>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    // Both parts need at least two numbers to add.
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let roots = parse_trees(input)?;
+
+        if roots.len() < 2 {
+            return Err(ParseError::new(
+                input,
+                input.len(),
+                ["another snailfish number"],
+            ));
+        }
+
+        Ok(roots)
+    }
+
+    fn solve_part_a(roots: &Self::Input<'_>) -> Self::AnswerA {
+        sum_trees(roots.clone()).magnitude()
+    }
+
+    fn solve_part_b(roots: &Self::Input<'_>) -> Self::AnswerB {
+        find_largest_magnitude_sum(roots, 2)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs;
 
     fn assert_explode(before: &str, after: &str) {
         let mut root = TreeNode::decode(before).unwrap();
@@ -275,7 +333,10 @@ mod tests {
     }
 
     fn assert_sum(s: &str, combined: &str) {
-        assert_eq!(sum_trees(parse_trees(s)).encode().unwrap(), combined);
+        assert_eq!(
+            sum_trees(parse_trees(s).unwrap()).encode().unwrap(),
+            combined
+        );
     }
 
     #[test]
@@ -293,7 +354,7 @@ mod tests {
             "[[[[5,0],[7,4]],[5,5]],[6,6]]",
         );
 
-        let slightly_larger_example = vec![
+        let slightly_larger_example = [
             "[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]",
             "[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]",
             "[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]",
@@ -313,14 +374,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let generated = solution::generate::<Day18>(seed, 20);
+            let roots = Day18::parse(&generated.input).unwrap();
+            assert_eq!(roots.len(), 20);
+
+            // Reduced numbers come through the reduction unchanged.
+            for (root, line) in roots.iter().zip(generated.input.lines()) {
+                let mut reduced = root.clone();
+                assert!(!reduced.explode(EXPLODE_DEPTH).0);
+                assert!(!reduced.split());
+                assert_eq!(reduced.encode().unwrap(), line);
+            }
+
+            assert!(Day18::solve_part_b(&roots) >= Day18::solve_part_a(&roots[..2].to_vec()));
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day18::parse("[1,2]\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, vec!["another snailfish number"]);
+
+        let error = Day18::parse("[1,2]\n[[250,3],4]\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.expected, vec!["regular number from 0 to 9"]);
+
+        let error = Day18::parse("[1,2]\n[1,2\n").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.expected, vec!["snailfish number"]);
+    }
+
     #[test]
     fn test_example() {
         let s = fs::read_to_string("input-test").unwrap();
-        let root = sum_trees(parse_trees(&s));
+        let roots = parse_trees(&s).unwrap();
+        let root = sum_trees(roots.clone());
         assert_eq!(
             root.encode().unwrap(),
             "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]"
         );
         assert_eq!(root.magnitude(), 4140);
+        assert_eq!(find_largest_magnitude_sum(&roots, 2), 3993);
     }
 }
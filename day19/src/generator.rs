@@ -0,0 +1,230 @@
+use crate::{Day19, Matrix, Vector};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde_json::json;
+use solution::{Generated, Generator};
+use std::collections::{HashMap, HashSet};
+
+// How far a scanner can see along each axis.
+const RANGE: isize = 1000;
+
+// Overlapping scanners always see exactly this many of the same beacons, which is what the puzzle
+// promises for the scanners that can be matched up.
+const OVERLAP: usize = 12;
+
+// The farthest apart that neighboring scanners are placed along the axis that separates them, and
+// the closest that any two scanners can be, so that neither one is inside the other's region.
+const MAX_SPACING: isize = 1250;
+const MIN_SPACING: isize = 1050;
+
+// How far neighboring scanners can be off from each other along the other axes.
+const JITTER: isize = 150;
+
+// How many times to try placing something at random before starting over.
+const ATTEMPTS: usize = 10000;
+
+// The more scanners there are, the likelier it is that one of them is crowded in by the others so
+// that there's no room for its beacons, and everything has to start over. Past about this many,
+// that happens more often than not, and the reports take a long time to solve anyway.
+const MAX_SIZE: usize = 1000;
+
+// Scanners by the cube of the sea they're in, where the cubes are as wide as scanners are spaced.
+// Anything a scanner can see, and any scanner too close to it, is nearer than that along every
+// axis, so it's in the same cube or one beside it.
+#[derive(Debug, Default)]
+struct Cubes(HashMap<[isize; 3], Vec<usize>>);
+
+impl Cubes {
+    fn cube(point: &Vector) -> [isize; 3] {
+        point.0.map(|c| c.div_euclid(MIN_SPACING))
+    }
+
+    fn insert(&mut self, scanner: usize, position: &Vector) {
+        self.0
+            .entry(Self::cube(position))
+            .or_default()
+            .push(scanner);
+    }
+
+    fn near(&self, point: &Vector) -> Vec<usize> {
+        let [x, y, z] = Self::cube(point);
+        let mut near: Vec<usize> = Default::default();
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                for dz in -1..=1 {
+                    if let Some(scanners) = self.0.get(&[x + dx, y + dy, z + dz]) {
+                        near.extend(scanners);
+                    }
+                }
+            }
+        }
+        near
+    }
+}
+
+fn visible(scanner: &Vector, beacon: &Vector) -> bool {
+    (*beacon - *scanner).iter().all(|c| c.abs() <= RANGE)
+}
+
+fn random_point<R: Rng + ?Sized>(rng: &mut R, min: &Vector, max: &Vector) -> Vector {
+    Vector::new(
+        rng.gen_range(min.x()..=max.x()),
+        rng.gen_range(min.y()..=max.y()),
+        rng.gen_range(min.z()..=max.z()),
+    )
+}
+
+// Places each scanner next to one that's already placed, so that they form a tree, giving the
+// positions and the pairs of neighbors.
+fn place_scanners<R: Rng + ?Sized>(rng: &mut R, size: usize) -> (Vec<Vector>, Vec<[usize; 2]>) {
+    let mut scanners: Vec<Vector> = vec![Default::default()];
+    let mut neighbors: Vec<[usize; 2]> = Default::default();
+    let mut cubes: Cubes = Default::default();
+    cubes.insert(0, &scanners[0]);
+
+    while scanners.len() < size {
+        for _ in 0..ATTEMPTS {
+            let neighbor = rng.gen_range(0..scanners.len());
+
+            let axis = rng.gen_range(0..3);
+            let mut offset = random_point(
+                rng,
+                &Vector::new(-JITTER, -JITTER, -JITTER),
+                &Vector::new(JITTER, JITTER, JITTER),
+            );
+            offset[axis] = rng.gen_range(MIN_SPACING..=MAX_SPACING) * [-1, 1].choose(rng).unwrap();
+
+            let position = scanners[neighbor] + offset;
+            let too_close = cubes.near(&position).into_iter().any(|i| {
+                (position - scanners[i])
+                    .iter()
+                    .all(|c| c.abs() < MIN_SPACING)
+            });
+
+            if !too_close {
+                cubes.insert(scanners.len(), &position);
+                neighbors.push([neighbor, scanners.len()]);
+                scanners.push(position);
+                break;
+            }
+        }
+    }
+
+    (scanners, neighbors)
+}
+
+// Places `count` beacons that can be seen by every one of the `seen_by` scanners and by none of
+// the others, or gives up if there's not enough room for them.
+fn place_beacons<R: Rng + ?Sized>(
+    rng: &mut R,
+    scanners: &[Vector],
+    cubes: &Cubes,
+    seen_by: &[usize],
+    count: usize,
+    beacons: &mut HashSet<Vector>,
+) -> bool {
+    let mut min = Vector::new(isize::MIN, isize::MIN, isize::MIN);
+    let mut max = Vector::new(isize::MAX, isize::MAX, isize::MAX);
+
+    for &i in seen_by.iter() {
+        for axis in 0..3 {
+            min[axis] = min[axis].max(scanners[i][axis] - RANGE);
+            max[axis] = max[axis].min(scanners[i][axis] + RANGE);
+        }
+    }
+
+    let mut placed = 0;
+
+    for _ in 0..ATTEMPTS {
+        if placed == count {
+            break;
+        }
+
+        let beacon = random_point(rng, &min, &max);
+        let seen = cubes
+            .near(&beacon)
+            .into_iter()
+            .all(|i| visible(&scanners[i], &beacon) == seen_by.contains(&i));
+
+        if seen && beacons.insert(beacon) {
+            placed += 1;
+        }
+    }
+
+    placed == count
+}
+
+/// Scanner reports, where the size is the number of scanners, up to a thousand. Neighboring
+/// scanners overlap by exactly twelve beacons. The truth is the position of each scanner relative
+/// to the first one, and the number of beacons, in the same shape as the extras.
+impl Generator for Day19 {
+    const DEFAULT_SIZE: usize = 35;
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+        let size = size.clamp(1, MAX_SIZE);
+
+        let (scanners, cubes, beacons) = loop {
+            let (scanners, neighbors) = place_scanners(rng, size);
+            let mut cubes: Cubes = Default::default();
+            for (i, scanner) in scanners.iter().enumerate() {
+                cubes.insert(i, scanner);
+            }
+            let mut beacons: HashSet<Vector> = Default::default();
+
+            let shared = neighbors
+                .iter()
+                .all(|pair| place_beacons(rng, &scanners, &cubes, pair, OVERLAP, &mut beacons));
+            let unshared = (0..scanners.len()).all(|i| {
+                let count = rng.gen_range(2..=OVERLAP);
+                place_beacons(rng, &scanners, &cubes, &[i], count, &mut beacons)
+            });
+
+            if shared && unshared {
+                break (scanners, cubes, beacons);
+            }
+        };
+
+        let mut seen: Vec<Vec<Vector>> = vec![Default::default(); scanners.len()];
+        for beacon in beacons.iter() {
+            for i in cubes.near(beacon) {
+                if visible(&scanners[i], beacon) {
+                    seen[i].push(*beacon - scanners[i]);
+                }
+            }
+        }
+
+        // The first scanner isn't rotated, since the others are all found relative to it.
+        let rotations = Matrix::rotations();
+        let mut reports: Vec<String> = Default::default();
+
+        for (i, seen) in seen.into_iter().enumerate() {
+            let rotation = if i == 0 {
+                Matrix::identity()
+            } else {
+                *rotations.choose(rng).unwrap()
+            };
+
+            let mut seen: Vec<Vector> = seen.into_iter().map(|beacon| beacon * rotation).collect();
+            // Sets don't have a reliable order, so it's sorted first to keep the same seed giving
+            // the same input.
+            seen.sort_unstable();
+            seen.shuffle(rng);
+
+            let mut report = format!("--- scanner {} ---", i);
+            for beacon in seen {
+                report += &format!("\n{},{},{}", beacon.x(), beacon.y(), beacon.z());
+            }
+            reports.push(report);
+        }
+
+        let positions: Vec<_> = scanners.iter().map(|position| position.0).collect();
+
+        Generated {
+            input: reports.join("\n\n") + "\n",
+            truth: Some(json!({
+                "scanners": positions,
+                "beacons": beacons.len(),
+            })),
+        }
+    }
+}
@@ -1,46 +1,22 @@
-mod matrix;
+mod generator;
 mod scanner;
-mod vector;
 
-use crate::matrix::{Matrix, IDENTITY};
-use crate::scanner::{Fingerprint, Scanner};
-use crate::vector::Vector;
+use crate::scanner::{scanner_parser, Fingerprint, Scanner};
 use itertools::Itertools;
+use serde_json::{json, Value};
+use solution::{ParseError, Solution};
 use std::collections::{HashMap, HashSet};
-use std::fs;
 
-fn manhattan_distance(a: &Vector, b: &Vector) -> usize {
-    (*a - *b).iter().map(|c| c.abs() as usize).sum()
-}
-
-fn generate_rotations() -> Vec<Matrix> {
-    let mut rotations: Vec<Matrix> = Default::default();
-
-    let mut m = IDENTITY;
-
-    let roll = Matrix([Vector([0, 0, -1]), Vector([0, 1, 0]), Vector([1, 0, 0])]);
-    let turn_cw = Matrix([Vector([1, 0, 0]), Vector([0, 0, -1]), Vector([0, 1, 0])]);
-    let turn_ccw = Matrix([Vector([1, 0, 0]), Vector([0, 0, 1]), Vector([0, -1, 0])]);
-
-    for i in 0..6 {
-        rotations.push(m);
-
-        for _ in 0..3 {
-            m *= if i % 2 == 0 { turn_cw } else { turn_ccw };
-            rotations.push(m);
-        }
-
-        m *= roll;
-    }
+type Vector = geometry::Vector<3>;
+type Matrix = geometry::Matrix<3>;
 
-    rotations
-}
-
-fn assemble_map(scanners: &[Scanner]) -> (HashSet<Vector>, HashSet<Vector>) {
-    // As scanners are confirmed, they will be added this map. As the first scanner is serving as
-    // the origin, its position is already known.
-    let mut scanner_map: HashSet<Vector> = Default::default();
-    scanner_map.insert(Vector([0, 0, 0]));
+// Scanners that don't overlap enough with any of the others can't be placed, so they're left
+// without a position, and their beacons are left off the map.
+fn assemble_map(scanners: &[Scanner]) -> (Vec<Option<Vector>>, HashSet<Vector>) {
+    // As scanners are confirmed, their positions will be filled in on this map. As the first
+    // scanner is serving as the origin, its position is already known.
+    let mut scanner_map: Vec<Option<Vector>> = vec![None; scanners.len()];
+    scanner_map[0] = Some(Default::default());
 
     // Also, when a scanner is confirmed, add its beacons to this map, to keep track of them all.
     // Since the first scanner is already confirmed, initialize this map with its beacons.
@@ -56,10 +32,10 @@ fn assemble_map(scanners: &[Scanner]) -> (HashSet<Vector>, HashSet<Vector>) {
     let mut unknown: Vec<usize> = (1..scanners.len()).collect();
 
     // Keep track of the rotation index used to reorient each scanner as they are resolved.
-    let mut orientations: Vec<usize> = std::iter::repeat(0).take(scanners.len()).collect();
+    let mut orientations: Vec<usize> = vec![0; scanners.len()];
 
     // Cache every rotation for each scanner and its corresponding fingerprint.
-    let rotations = generate_rotations();
+    let rotations = Matrix::rotations();
     let mut scanner_orientations: Vec<Vec<Scanner>> = Default::default();
     let mut fingerprint_orientations: Vec<Vec<Fingerprint>> = Default::default();
 
@@ -91,7 +67,9 @@ fn assemble_map(scanners: &[Scanner]) -> (HashSet<Vector>, HashSet<Vector>) {
         fingerprint_orientations.push(rotated_fingerprints);
     }
 
-    while !unknown.is_empty() {
+    // Once there are no newly confirmed scanners left to check against, any that are still unknown
+    // never will be.
+    while !unknown.is_empty() && !known.is_empty() {
         // At the end of this loop iteration, the known and unknown sets need to be updated to
         // reflect updates to the scanners' statuses. Since a mutable collection cannot be updated
         // as it's being iterated, they will be updated at the end of the loops based on these
@@ -102,9 +80,7 @@ fn assemble_map(scanners: &[Scanner]) -> (HashSet<Vector>, HashSet<Vector>) {
         // remaining scanners.
         let mut updated_scanners: HashMap<usize, Scanner> = Default::default();
 
-        while !known.is_empty() {
-            let i = known.pop().unwrap();
-
+        while let Some(i) = known.pop() {
             let known_orientation = orientations[i];
             let known_scanner = &scanner_orientations[i][known_orientation];
 
@@ -148,7 +124,7 @@ fn assemble_map(scanners: &[Scanner]) -> (HashSet<Vector>, HashSet<Vector>) {
                                     .count();
 
                                 if overlap == 12 {
-                                    scanner_map.insert(scanner_position);
+                                    scanner_map[j] = Some(scanner_position);
                                     for beacon in transformed_scanner.iter() {
                                         beacon_map.insert(*beacon);
                                     }
@@ -176,37 +152,124 @@ fn assemble_map(scanners: &[Scanner]) -> (HashSet<Vector>, HashSet<Vector>) {
     (scanner_map, beacon_map)
 }
 
-fn greatest_manhattan_distance(points: &HashSet<Vector>) -> usize {
+// Only the scanners that could be placed count, and with fewer than two there's no distance.
+fn greatest_manhattan_distance(points: &[Option<Vector>]) -> usize {
     points
         .iter()
+        .flatten()
         .combinations(2)
-        .map(|pair| manhattan_distance(pair[0], pair[1]))
+        .map(|pair| pair[0].manhattan_distance(pair[1]))
         .max()
-        .unwrap()
+        .unwrap_or(0)
 }
 
-fn parse_scanners(s: &str) -> Vec<Scanner> {
-    s.split("\n\n")
-        .map(|block| block.parse().unwrap())
-        .collect()
+fn parse_scanners(s: &str) -> Result<Vec<Scanner>, ParseError> {
+    scanner_parser::parse(s).map_err(|error| ParseError::from_peg(s, error))
 }
 
-fn main() {
-    let s = fs::read_to_string("input").unwrap();
-    let scanners = parse_scanners(&s);
-    let (scanner_map, _) = assemble_map(&scanners);
-    dbg!(greatest_manhattan_distance(&scanner_map));
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input<'a> = Vec<Scanner>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_scanners(input)
+    }
+
+    fn solve_part_a(scanners: &Self::Input<'_>) -> Self::AnswerA {
+        let (_, beacon_map) = assemble_map(scanners);
+        beacon_map.len()
+    }
+
+    fn solve_part_b(scanners: &Self::Input<'_>) -> Self::AnswerB {
+        let (scanner_map, _) = assemble_map(scanners);
+        greatest_manhattan_distance(&scanner_map)
+    }
+
+    fn extras(scanners: &Self::Input<'_>) -> Option<Value> {
+        let (scanner_map, beacon_map) = assemble_map(scanners);
+        // Scanners that couldn't be placed are null, so the rest keep their indexes.
+        let positions: Vec<_> = scanner_map
+            .iter()
+            .map(|position| position.map(|position| position.0))
+            .collect();
+
+        Some(json!({
+            "scanners": positions,
+            "beacons": beacon_map.len(),
+        }))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs;
 
     #[test]
     fn test_example() {
         let s = fs::read_to_string("input-test").unwrap();
-        let scanners = parse_scanners(&s);
-        let (scanner_map, _) = assemble_map(&scanners);
+        let scanners = parse_scanners(&s).unwrap();
+        let (scanner_map, beacon_map) = assemble_map(&scanners);
+        assert_eq!(beacon_map.len(), 79);
         assert_eq!(greatest_manhattan_distance(&scanner_map), 3621);
     }
+
+    #[test]
+    fn test_extras() {
+        let s = fs::read_to_string("input-test").unwrap();
+        let scanners = parse_scanners(&s).unwrap();
+        assert_eq!(
+            Day19::extras(&scanners),
+            Some(json!({
+                "scanners": [
+                    [0, 0, 0],
+                    [68, -1246, -43],
+                    [1105, -1205, 1229],
+                    [-92, -2380, -20],
+                    [-20, -1133, 1061],
+                ],
+                "beacons": 79,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_unplaced_scanner() {
+        // A scanner that sees none of the same beacons as the others.
+        let s = fs::read_to_string("input-test").unwrap()
+            + "\n--- scanner 5 ---\n1,2,3\n4,5,6\n7,8,9\n";
+        let scanners = parse_scanners(&s).unwrap();
+        let extras = Day19::extras(&scanners).unwrap();
+        assert_eq!(extras["scanners"][4], json!([-20, -1133, 1061]));
+        assert_eq!(extras["scanners"][5], Value::Null);
+        assert_eq!(extras["beacons"], 79);
+        assert_eq!(Day19::solve_part_b(&scanners), 3621);
+
+        let scanners = parse_scanners("--- scanner 0 ---\n1,2,3\n").unwrap();
+        assert_eq!(Day19::solve_part_b(&scanners), 0);
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..3 {
+            let generated = solution::generate::<Day19>(seed, 6);
+            let scanners = Day19::parse(&generated.input).unwrap();
+            assert_eq!(scanners.len(), 6);
+            assert_eq!(Day19::extras(&scanners), generated.truth);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error =
+            parse_scanners("--- scanner 0 ---\n1,2,3\n\n--- scanner 1 ---\n4,5\n").unwrap_err();
+        assert_eq!((error.line, error.column), (5, 4));
+        assert_eq!(error.expected, vec!["\",\"", "['0'..='9']"]);
+
+        let error = parse_scanners("").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+    }
 }
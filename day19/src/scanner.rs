@@ -1,6 +1,6 @@
-use crate::matrix::Matrix;
-use crate::vector::{Value, Vector};
+use crate::{Matrix, Vector};
 use itertools::Itertools;
+use solution::ParseError;
 use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 
@@ -32,7 +32,7 @@ impl Scanner {
         for combo in self.0.iter().combinations(2) {
             let distance = *combo[0] - *combo[1];
 
-            let mut components: Vec<Value> = distance.iter().map(|c| c.abs()).collect();
+            let mut components = distance.0.map(|c| c.abs());
             components.sort_unstable();
 
             let normalized_distance = Vector::from(components);
@@ -46,17 +46,27 @@ impl Scanner {
 }
 
 impl FromStr for Scanner {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
-    fn from_str(s: &str) -> anyhow::Result<Self> {
-        Ok(scanner_parser::parse(s)?)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        scanner_parser::parse_scanner(s).map_err(|error| ParseError::from_peg(s, error))
     }
 }
 
 peg::parser! {
-    grammar scanner_parser() for str {
-        pub rule parse() -> Scanner
-            = "--- scanner " parse_number() " ---\n" beacons:parse_locations() "\n"? {
+    pub grammar scanner_parser() for str {
+        pub rule parse() -> Vec<Scanner>
+            = scanners:(scanner() ++ "\n\n") "\n"? {
+                scanners
+            }
+
+        pub rule parse_scanner() -> Scanner
+            = scanner:scanner() "\n"? {
+                scanner
+            }
+
+        rule scanner() -> Scanner
+            = "--- scanner " parse_number() " ---\n" beacons:parse_locations() {
                 Scanner(beacons.into_iter().collect())
             }
 
@@ -65,12 +75,12 @@ peg::parser! {
 
         rule parse_location() -> Vector
             = x:parse_number() "," y:parse_number() "," z:parse_number() {
-                Vector([x,y,z])
+                Vector::new(x, y, z)
             }
 
         rule parse_number() -> isize
-            = s:$("-"? ['0'..='9']+) {
-                s.parse().unwrap()
+            = s:$("-"? ['0'..='9']+) {?
+                s.parse().or(Err("number"))
             }
     }
 }
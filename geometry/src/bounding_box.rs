@@ -0,0 +1,101 @@
+use crate::vector::{Value, Vector};
+
+/// The smallest axis-aligned box containing a set of points. Both corners are inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundingBox<const N: usize> {
+    pub min: Vector<N>,
+    pub max: Vector<N>,
+}
+
+impl<const N: usize> BoundingBox<N> {
+    /// The box spanning two opposite corners, given in any order.
+    pub fn new(a: Vector<N>, b: Vector<N>) -> Self {
+        let mut result = Self { min: a, max: a };
+        result.extend(b);
+        result
+    }
+
+    /// Returns `None` if there are no points.
+    pub fn from_points(points: impl IntoIterator<Item = Vector<N>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let mut result = Self {
+            min: first,
+            max: first,
+        };
+
+        for point in points {
+            result.extend(point);
+        }
+
+        Some(result)
+    }
+
+    /// Grows the box just enough to contain `point`.
+    pub fn extend(&mut self, point: Vector<N>) {
+        for i in 0..N {
+            self.min[i] = self.min[i].min(point[i]);
+            self.max[i] = self.max[i].max(point[i]);
+        }
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = *self;
+        result.extend(other.min);
+        result.extend(other.max);
+        result
+    }
+
+    pub fn contains(&self, point: &Vector<N>) -> bool {
+        (0..N).all(|i| self.min[i] <= point[i] && point[i] <= self.max[i])
+    }
+
+    /// The number of lattice points along each axis.
+    pub fn size(&self) -> Vector<N> {
+        self.max - self.min + Vector([1; N])
+    }
+
+    /// The number of lattice points inside the box.
+    pub fn volume(&self) -> Value {
+        self.size().iter().product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_points() {
+        assert_eq!(BoundingBox::<2>::from_points([]), None);
+        assert_eq!(
+            BoundingBox::from_points([Vector([3, -1]), Vector([-2, 4]), Vector([0, 0])]),
+            Some(BoundingBox {
+                min: Vector([-2, -1]),
+                max: Vector([3, 4])
+            })
+        );
+    }
+
+    #[test]
+    fn test_contains() {
+        let bounds = BoundingBox::new(Vector([20, -5]), Vector([30, -10]));
+        assert!(bounds.contains(&Vector([20, -5])));
+        assert!(bounds.contains(&Vector([30, -10])));
+        assert!(bounds.contains(&Vector([25, -7])));
+        assert!(!bounds.contains(&Vector([19, -7])));
+        assert!(!bounds.contains(&Vector([25, -4])));
+        assert_eq!(bounds.size(), Vector([11, 6]));
+        assert_eq!(bounds.volume(), 66);
+    }
+
+    #[test]
+    fn test_union() {
+        let a = BoundingBox::new(Vector([0, 0, 0]), Vector([1, 1, 1]));
+        let b = BoundingBox::new(Vector([-1, 2, 0]), Vector([0, 3, 0]));
+        assert_eq!(
+            a.union(&b),
+            BoundingBox::new(Vector([-1, 0, 0]), Vector([1, 3, 1]))
+        );
+    }
+}
@@ -0,0 +1,7 @@
+mod bounding_box;
+mod matrix;
+mod vector;
+
+pub use crate::bounding_box::BoundingBox;
+pub use crate::matrix::Matrix;
+pub use crate::vector::{gcd, Value, Vector};
@@ -0,0 +1,173 @@
+use crate::vector::Vector;
+use std::ops::{Index, IndexMut, Mul, MulAssign};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Matrix<const N: usize>(pub [Vector<N>; N]);
+
+impl<const N: usize> Default for Matrix<N> {
+    fn default() -> Self {
+        Self([Default::default(); N])
+    }
+}
+
+impl<const N: usize> Matrix<N> {
+    pub fn identity() -> Self {
+        let mut result: Self = Default::default();
+
+        for i in 0..N {
+            result[i][i] = 1;
+        }
+
+        result
+    }
+
+    /// Every rotation that maps the axes onto the axes, starting with the identity. These are the
+    /// signed permutation matrices with a determinant of one, so there are 4 in two dimensions and
+    /// 24 in three.
+    pub fn rotations() -> Vec<Self> {
+        let mut rotations = Vec::new();
+
+        for (permutation, parity) in permutations::<N>() {
+            // Each bit of the mask flips the sign of one axis, so the first mask keeps them all.
+            for mask in 0..1usize << N {
+                // Flipping an odd number of axes is a reflection, unless the permutation was
+                // already one, in which case it is undone.
+                if !(mask.count_ones() as usize + parity).is_multiple_of(2) {
+                    continue;
+                }
+
+                let mut rotation: Self = Default::default();
+
+                for (row, &column) in permutation.iter().enumerate() {
+                    rotation[row][column] = if mask & (1 << row) == 0 { 1 } else { -1 };
+                }
+
+                rotations.push(rotation);
+            }
+        }
+
+        rotations
+    }
+}
+
+/// Every permutation of `0..N` in lexicographic order, paired with the number of swaps it took to
+/// reach it (mod 2) so that odd permutations can be told apart from even ones.
+fn permutations<const N: usize>() -> Vec<([usize; N], usize)> {
+    let mut permutation = [0; N];
+
+    for (i, value) in permutation.iter_mut().enumerate() {
+        *value = i;
+    }
+
+    let mut result = Vec::new();
+    let mut parity = 0;
+
+    loop {
+        result.push((permutation, parity));
+
+        // The standard next-permutation algorithm: find the rightmost ascent, swap its head with
+        // the rightmost value larger than it, then reverse everything after it.
+        let i = match (1..N).rev().find(|&i| permutation[i - 1] < permutation[i]) {
+            Some(i) => i - 1,
+            None => return result,
+        };
+        let j = (i + 1..N)
+            .rev()
+            .find(|&j| permutation[j] > permutation[i])
+            .unwrap();
+
+        permutation.swap(i, j);
+        permutation[i + 1..].reverse();
+
+        let reversed = N - i - 1;
+        parity = (parity + 1 + reversed / 2) % 2;
+    }
+}
+
+impl<const N: usize> Index<usize> for Matrix<N> {
+    type Output = Vector<N>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Matrix<N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<const N: usize> Mul<Self> for Matrix<N> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        let mut result: Self = Default::default();
+
+        for i in 0..N {
+            result[i] = self[i] * other;
+        }
+
+        result
+    }
+}
+
+impl<const N: usize> MulAssign<Self> for Matrix<N> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_mul() {
+        assert_eq!(
+            Matrix([Vector([1, 2, 3]), Vector([4, 5, 6]), Vector([7, 8, 9])])
+                * Matrix([Vector([9, 8, 7]), Vector([6, 5, 4]), Vector([3, 2, 1])]),
+            Matrix([
+                Vector([30, 24, 18]),
+                Vector([84, 69, 54]),
+                Vector([138, 114, 90])
+            ])
+        );
+        assert_eq!(
+            Matrix([Vector([1, 2]), Vector([3, 4])]) * Matrix::identity(),
+            Matrix([Vector([1, 2]), Vector([3, 4])])
+        );
+    }
+
+    fn assert_rotation_group<const N: usize>(order: usize) {
+        let rotations = Matrix::<N>::rotations();
+        let unique: HashSet<Matrix<N>> = rotations.iter().copied().collect();
+
+        assert_eq!(rotations.len(), order);
+        assert_eq!(unique.len(), order);
+        assert_eq!(rotations[0], Matrix::identity());
+
+        // Composing any two rotations has to give another one.
+        for &a in rotations.iter() {
+            for &b in rotations.iter() {
+                assert!(unique.contains(&(a * b)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotations() {
+        assert_rotation_group::<2>(4);
+        assert_rotation_group::<3>(24);
+    }
+
+    #[test]
+    fn test_rotations_2d() {
+        // A quarter turn, in either direction, has to be one of them.
+        let rotations = Matrix::<2>::rotations();
+        assert!(rotations.contains(&Matrix([Vector([0, 1]), Vector([-1, 0])])));
+        assert!(rotations.contains(&Matrix([Vector([0, -1]), Vector([1, 0])])));
+        assert!(!rotations.contains(&Matrix([Vector([0, 1]), Vector([1, 0])])));
+    }
+}
@@ -0,0 +1,258 @@
+use crate::matrix::Matrix;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::ops::{Index, IndexMut};
+
+pub type Value = isize;
+
+/// The greatest common divisor of `a` and `b`, which may be negative if either of them is.
+pub fn gcd(a: Value, b: Value) -> Value {
+    if a == 0 {
+        return b;
+    }
+
+    if b == 0 {
+        return a;
+    }
+
+    gcd(b, a % b)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vector<const N: usize>(pub [Value; N]);
+
+impl<const N: usize> Default for Vector<N> {
+    fn default() -> Self {
+        Self([0; N])
+    }
+}
+
+impl<const N: usize> Vector<N> {
+    pub fn iter(&self) -> impl Iterator<Item = &Value> {
+        self.0.iter()
+    }
+
+    pub fn dot(&self, other: &Self) -> Value {
+        self.iter().zip(other.iter()).map(|(a, b)| a * b).sum()
+    }
+
+    pub fn manhattan_length(&self) -> usize {
+        self.iter().map(|c| c.unsigned_abs()).sum()
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> usize {
+        (*self - *other).manhattan_length()
+    }
+
+    /// The smallest vector with integer components that points the same way as this one. Stepping
+    /// by it from one lattice point will land on every lattice point along the way.
+    pub fn direction(&self) -> Self {
+        let divisor = self.iter().fold(0, |divisor, &c| gcd(divisor, c)).abs();
+
+        if divisor == 0 {
+            return *self;
+        }
+
+        *self / divisor
+    }
+}
+
+impl Vector<2> {
+    pub const fn new(x: Value, y: Value) -> Self {
+        Self([x, y])
+    }
+}
+
+impl Vector<3> {
+    pub const fn new(x: Value, y: Value, z: Value) -> Self {
+        Self([x, y, z])
+    }
+}
+
+impl<const N: usize> Vector<N> {
+    pub fn x(&self) -> Value {
+        self[0]
+    }
+
+    pub fn y(&self) -> Value {
+        self[1]
+    }
+
+    pub fn z(&self) -> Value {
+        self[2]
+    }
+}
+
+impl<const N: usize> From<[Value; N]> for Vector<N> {
+    fn from(values: [Value; N]) -> Self {
+        Self(values)
+    }
+}
+
+impl<const N: usize> Index<usize> for Vector<N> {
+    type Output = Value;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Vector<N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<const N: usize> Add<Self> for Vector<N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        let mut result = self;
+
+        for i in 0..N {
+            result[i] += other[i];
+        }
+
+        result
+    }
+}
+
+impl<const N: usize> AddAssign<Self> for Vector<N> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const N: usize> Sub<Self> for Vector<N> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        let mut result = self;
+
+        for i in 0..N {
+            result[i] -= other[i];
+        }
+
+        result
+    }
+}
+
+impl<const N: usize> SubAssign<Self> for Vector<N> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other
+    }
+}
+
+impl<const N: usize> Neg for Vector<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0.map(|c| -c))
+    }
+}
+
+impl<const N: usize> Mul<Value> for Vector<N> {
+    type Output = Self;
+
+    fn mul(self, scale: Value) -> Self::Output {
+        Self(self.0.map(|c| c * scale))
+    }
+}
+
+impl<const N: usize> MulAssign<Value> for Vector<N> {
+    fn mul_assign(&mut self, scale: Value) {
+        *self = *self * scale
+    }
+}
+
+impl<const N: usize> Div<Value> for Vector<N> {
+    type Output = Self;
+
+    fn div(self, divisor: Value) -> Self::Output {
+        Self(self.0.map(|c| c / divisor))
+    }
+}
+
+// Vectors are treated as rows, so a vector is transformed by multiplying it on the left of a
+// matrix.
+impl<const N: usize> Mul<Matrix<N>> for Vector<N> {
+    type Output = Self;
+
+    fn mul(self, other: Matrix<N>) -> Self::Output {
+        let mut result: Self = Default::default();
+
+        for i in 0..N {
+            for j in 0..N {
+                result[i] += self[j] * other[j][i];
+            }
+        }
+
+        result
+    }
+}
+
+impl<const N: usize> MulAssign<Matrix<N>> for Vector<N> {
+    fn mul_assign(&mut self, other: Matrix<N>) {
+        *self = *self * other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd(1, 0), 1);
+        assert_eq!(gcd(0, 1), 1);
+        assert_eq!(gcd(10, 25), 5);
+    }
+
+    #[test]
+    fn test_add_vector() {
+        assert_eq!(Vector([1, 2, 3]) + Vector([4, 5, 6]), Vector([5, 7, 9]));
+    }
+
+    #[test]
+    fn test_sub_vector() {
+        assert_eq!(Vector([6, 8, 10]) - Vector([5, 6, 7]), Vector([1, 2, 3]));
+    }
+
+    #[test]
+    fn test_scale_vector() {
+        assert_eq!(Vector([1, -2]) * 3, Vector([3, -6]));
+        assert_eq!(-Vector([1, -2]), Vector([-1, 2]));
+    }
+
+    #[test]
+    fn test_dot() {
+        assert_eq!(Vector([1, 2, 3]).dot(&Vector([4, -5, 6])), 12);
+    }
+
+    #[test]
+    fn test_manhattan_distance() {
+        assert_eq!(Vector([0, 0]).manhattan_distance(&Vector([0, 0])), 0);
+        assert_eq!(
+            Vector([1105, -1205, 1229]).manhattan_distance(&Vector([-92, -2380, -20])),
+            3621
+        );
+    }
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Vector([0, 0]).direction(), Vector([0, 0]));
+        assert_eq!(Vector([5, 0]).direction(), Vector([1, 0]));
+        assert_eq!(Vector([0, -5]).direction(), Vector([0, -1]));
+        assert_eq!(Vector([-5, -5]).direction(), Vector([-1, -1]));
+        assert_eq!(Vector([4, -6]).direction(), Vector([2, -3]));
+        assert_eq!(Vector([3, 6, -9]).direction(), Vector([1, 2, -3]));
+    }
+
+    #[test]
+    fn test_mul_matrix() {
+        assert_eq!(
+            Vector([1, 2, 3]) * Matrix([Vector([1, 2, 3]), Vector([4, 5, 6]), Vector([7, 8, 9]),]),
+            Vector([1 + 2 * 4 + 3 * 7, 2 + 2 * 5 + 3 * 8, 3 + 2 * 6 + 3 * 9])
+        );
+    }
+}
@@ -0,0 +1,366 @@
+use crate::location::{Location, Locations, Neighborhood};
+use solution::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid with a value in every cell, stored in row-major order.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    height: usize,
+    width: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_cells(height: usize, width: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), height * width);
+        Self {
+            height,
+            width,
+            cells,
+        }
+    }
+
+    /// Parses one row per line, converting each character with `parse_cell`. Every row has to be
+    /// as wide as the first one. The `expected` description of a cell is used in errors.
+    pub fn parse<F>(s: &str, expected: &str, parse_cell: F) -> Result<Self, ParseError>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let lines: Vec<&str> = s.lines().map(|line| line.trim()).collect();
+
+        let height = lines.len();
+        let width = lines.first().map_or(0, |line| line.chars().count());
+
+        if width == 0 {
+            return Err(ParseError::new(s, 0, [expected]));
+        }
+
+        let mut cells = Vec::with_capacity(height * width);
+
+        for line in lines.into_iter() {
+            let mut columns = line.char_indices();
+            let start = cells.len();
+
+            for (i, c) in columns.by_ref().take(width) {
+                let cell =
+                    parse_cell(c).ok_or_else(|| ParseError::at(s, &line[i..], [expected]))?;
+                cells.push(cell);
+            }
+
+            if cells.len() - start < width {
+                return Err(ParseError::at(s, &line[line.len()..], [expected]));
+            }
+
+            if let Some((i, _)) = columns.next() {
+                return Err(ParseError::at(s, &line[i..], ["end of line"]));
+            }
+        }
+
+        Ok(Self::from_cells(height, width, cells))
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, location: &Location) -> bool {
+        location.row < self.height && location.column < self.width
+    }
+
+    pub fn get_index(&self, location: &Location) -> Option<usize> {
+        if self.contains(location) {
+            Some(location.row * self.width + location.column)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, location: &Location) -> Option<&T> {
+        self.get_index(location).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, location: &Location) -> Option<&mut T> {
+        self.get_index(location).map(|index| &mut self.cells[index])
+    }
+
+    pub fn locations(&self) -> Locations {
+        Locations::new(self.height, self.width)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Location, &T)> {
+        self.locations().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// The neighbors of a location that lie within the grid, in clockwise order starting at
+    /// north. A location outside of the grid has no neighbors.
+    pub fn adjacent_locations(
+        &self,
+        location: &Location,
+        neighborhood: Neighborhood,
+    ) -> Vec<Location> {
+        if !self.contains(location) {
+            return Default::default();
+        }
+
+        location
+            .neighbors(neighborhood)
+            .filter(|adj_location| self.contains(adj_location))
+            .collect()
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(height: usize, width: usize, value: T) -> Self {
+        Self::from_cells(height, width, vec![value; height * width])
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a grid where every cell is a single decimal digit.
+    pub fn parse_digits(s: &str) -> Result<Self, ParseError> {
+        Self::parse(s, "digit", |c| c.to_digit(10).map(|digit| digit as u8))
+    }
+}
+
+impl Grid<char> {
+    pub fn parse_chars(s: &str) -> Result<Self, ParseError> {
+        Self::parse(s, "character", Some)
+    }
+}
+
+impl<T> Index<Location> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, location: Location) -> &Self::Output {
+        self.get(&location).unwrap()
+    }
+}
+
+impl<T> IndexMut<Location> for Grid<T> {
+    fn index_mut(&mut self, location: Location) -> &mut Self::Output {
+        self.get_mut(&location).unwrap()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (location, value) in self.iter() {
+            write!(f, "{}", value)?;
+            if location.column == self.width - 1 {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_parse_error(
+        result: Result<Grid<u8>, ParseError>,
+        at: (usize, usize),
+        expected: &str,
+    ) {
+        let error = result.unwrap_err();
+        assert_eq!((error.line, error.column), at);
+        assert_eq!(error.expected, vec![expected]);
+    }
+
+    #[test]
+    fn test_grid_contains() {
+        let grid = Grid::new(20, 10, 0);
+        assert!(grid.contains(&Location { row: 0, column: 0 }));
+        assert!(grid.contains(&Location { row: 5, column: 5 }));
+        assert!(grid.contains(&Location { row: 19, column: 9 }));
+        assert!(!grid.contains(&Location {
+            row: 20,
+            column: 10
+        }));
+    }
+
+    #[test]
+    fn test_grid_get_index() {
+        let grid = Grid::new(20, 10, 0);
+        assert_eq!(grid.get_index(&Location { row: 0, column: 0 }), Some(0));
+        assert_eq!(grid.get_index(&Location { row: 2, column: 5 }), Some(25));
+        assert_eq!(
+            grid.get_index(&Location {
+                row: 20,
+                column: 10
+            }),
+            None
+        );
+    }
+
+    #[test]
+    fn test_grid_adjacent_locations_four() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            grid.adjacent_locations(&Location { row: 0, column: 0 }, Neighborhood::Four),
+            vec![
+                Location { row: 0, column: 1 },
+                Location { row: 1, column: 0 }
+            ]
+        );
+        assert_eq!(
+            grid.adjacent_locations(&Location { row: 2, column: 2 }, Neighborhood::Four),
+            vec![
+                Location { row: 1, column: 2 },
+                Location { row: 2, column: 1 }
+            ]
+        );
+        assert_eq!(
+            grid.adjacent_locations(&Location { row: 1, column: 1 }, Neighborhood::Four),
+            vec![
+                Location { row: 0, column: 1 },
+                Location { row: 1, column: 2 },
+                Location { row: 2, column: 1 },
+                Location { row: 1, column: 0 },
+            ]
+        );
+        assert!(grid
+            .adjacent_locations(&Location { row: 3, column: 0 }, Neighborhood::Four)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_grid_adjacent_locations_eight() {
+        let grid = Grid::new(20, 10, 0);
+        // top-left corner
+        assert_eq!(
+            grid.adjacent_locations(&Location { row: 0, column: 0 }, Neighborhood::Eight),
+            vec![
+                Location { row: 0, column: 1 },
+                Location { row: 1, column: 1 },
+                Location { row: 1, column: 0 },
+            ]
+        );
+        // top-middle edge
+        assert_eq!(
+            grid.adjacent_locations(&Location { row: 0, column: 1 }, Neighborhood::Eight),
+            vec![
+                Location { row: 0, column: 2 },
+                Location { row: 1, column: 2 },
+                Location { row: 1, column: 1 },
+                Location { row: 1, column: 0 },
+                Location { row: 0, column: 0 },
+            ]
+        );
+        // top-right corner
+        assert_eq!(
+            grid.adjacent_locations(&Location { row: 0, column: 9 }, Neighborhood::Eight),
+            vec![
+                Location { row: 1, column: 9 },
+                Location { row: 1, column: 8 },
+                Location { row: 0, column: 8 },
+            ]
+        );
+        // middle-right edge
+        assert_eq!(
+            grid.adjacent_locations(&Location { row: 10, column: 9 }, Neighborhood::Eight),
+            vec![
+                Location { row: 9, column: 9 },
+                Location { row: 11, column: 9 },
+                Location { row: 11, column: 8 },
+                Location { row: 10, column: 8 },
+                Location { row: 9, column: 8 },
+            ]
+        );
+        // bottom-right corner
+        assert_eq!(
+            grid.adjacent_locations(&Location { row: 19, column: 9 }, Neighborhood::Eight),
+            vec![
+                Location { row: 18, column: 9 },
+                Location { row: 19, column: 8 },
+                Location { row: 18, column: 8 },
+            ]
+        );
+        // bottom-middle edge
+        assert_eq!(
+            grid.adjacent_locations(&Location { row: 19, column: 5 }, Neighborhood::Eight),
+            vec![
+                Location { row: 18, column: 5 },
+                Location { row: 18, column: 6 },
+                Location { row: 19, column: 6 },
+                Location { row: 19, column: 4 },
+                Location { row: 18, column: 4 },
+            ]
+        );
+        // bottom-left corner
+        assert_eq!(
+            grid.adjacent_locations(&Location { row: 19, column: 0 }, Neighborhood::Eight),
+            vec![
+                Location { row: 18, column: 0 },
+                Location { row: 18, column: 1 },
+                Location { row: 19, column: 1 },
+            ]
+        );
+        // middle-left edge
+        assert_eq!(
+            grid.adjacent_locations(&Location { row: 10, column: 0 }, Neighborhood::Eight),
+            vec![
+                Location { row: 9, column: 0 },
+                Location { row: 9, column: 1 },
+                Location { row: 10, column: 1 },
+                Location { row: 11, column: 1 },
+                Location { row: 11, column: 0 },
+            ]
+        );
+        // inner location
+        assert_eq!(
+            grid.adjacent_locations(&Location { row: 10, column: 5 }, Neighborhood::Eight),
+            vec![
+                Location { row: 9, column: 5 },
+                Location { row: 9, column: 6 },
+                Location { row: 10, column: 6 },
+                Location { row: 11, column: 6 },
+                Location { row: 11, column: 5 },
+                Location { row: 11, column: 4 },
+                Location { row: 10, column: 4 },
+                Location { row: 9, column: 4 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_grid_parse_digits() {
+        let grid = Grid::parse_digits("123\n456\n").unwrap();
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid[Location { row: 1, column: 0 }], 4);
+        assert_eq!(format!("{}", grid), "123\n456\n");
+
+        assert_parse_error(Grid::parse_digits(""), (1, 1), "digit");
+        assert_parse_error(Grid::parse_digits("\n123"), (1, 1), "digit");
+        assert_parse_error(Grid::parse_digits("123\n45"), (2, 3), "digit");
+        assert_parse_error(Grid::parse_digits("123\n4567"), (2, 4), "end of line");
+        assert_parse_error(Grid::parse_digits("123\n4x6"), (2, 2), "digit");
+    }
+
+    #[test]
+    fn test_grid_parse_chars() {
+        let grid = Grid::parse_chars("#.\n.#").unwrap();
+        assert_eq!(grid[Location { row: 0, column: 0 }], '#');
+        assert_eq!(grid[Location { row: 0, column: 1 }], '.');
+        assert_eq!(format!("{}", grid), "#.\n.#\n");
+    }
+}
@@ -0,0 +1,7 @@
+mod dense;
+mod location;
+mod sparse;
+
+pub use crate::dense::Grid;
+pub use crate::location::{Location, Locations, Neighborhood};
+pub use crate::sparse::SparseGrid;
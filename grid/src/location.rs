@@ -0,0 +1,190 @@
+use geometry::Vector;
+use std::ops::Add;
+
+// Listed clockwise, starting at north. Rows grow going south, so north is a negative y.
+const DIRECTIONS: [Vector<2>; 8] = [
+    Vector::<2>::new(0, -1),  // north
+    Vector::<2>::new(1, -1),  // northeast
+    Vector::<2>::new(1, 0),   // east
+    Vector::<2>::new(1, 1),   // southeast
+    Vector::<2>::new(0, 1),   // south
+    Vector::<2>::new(-1, 1),  // southwest
+    Vector::<2>::new(-1, 0),  // west
+    Vector::<2>::new(-1, -1), // northwest
+];
+
+const ORTHOGONAL_DIRECTIONS: [Vector<2>; 4] =
+    [DIRECTIONS[0], DIRECTIONS[2], DIRECTIONS[4], DIRECTIONS[6]];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighborhood {
+    /// The locations sharing an edge with a location.
+    Four,
+    /// The locations sharing an edge or a corner with a location.
+    Eight,
+}
+
+impl Neighborhood {
+    pub fn directions(&self) -> &'static [Vector<2>] {
+        match self {
+            Neighborhood::Four => &ORTHOGONAL_DIRECTIONS,
+            Neighborhood::Eight => &DIRECTIONS,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Location {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl Location {
+    pub fn new(row: usize, column: usize) -> Self {
+        Self { row, column }
+    }
+
+    /// Moves `v.x()` columns and `v.y()` rows. Returns `None` if the result would be above the
+    /// first row or left of the first column.
+    pub fn checked_add(self, v: Vector<2>) -> Option<Self> {
+        Some(Self {
+            row: self.row.checked_add_signed(v.y())?,
+            column: self.column.checked_add_signed(v.x())?,
+        })
+    }
+
+    /// The neighboring locations, in clockwise order starting at north, skipping any that would
+    /// be above the first row or left of the first column.
+    pub fn neighbors(self, neighborhood: Neighborhood) -> impl Iterator<Item = Location> {
+        neighborhood
+            .directions()
+            .iter()
+            .filter_map(move |&direction| self.checked_add(direction))
+    }
+}
+
+impl Add<Vector<2>> for Location {
+    type Output = Self;
+
+    fn add(self, v: Vector<2>) -> Self::Output {
+        self.checked_add(v).unwrap()
+    }
+}
+
+/// An iterator over every location in a grid, in row-major order.
+#[derive(Debug, Clone)]
+pub struct Locations {
+    height: usize,
+    width: usize,
+    row: usize,
+    column: usize,
+}
+
+impl Locations {
+    pub fn new(height: usize, width: usize) -> Self {
+        Self {
+            height,
+            width,
+            row: 0,
+            column: 0,
+        }
+    }
+}
+
+impl Iterator for Locations {
+    type Item = Location;
+
+    fn next(&mut self) -> Option<Location> {
+        if self.width == 0 || self.row == self.height {
+            return None;
+        }
+
+        let location = Location {
+            row: self.row,
+            column: self.column,
+        };
+
+        self.column += 1;
+
+        if self.column == self.width {
+            self.row += 1;
+            self.column = 0;
+        }
+
+        Some(location)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locations() {
+        let mut iter = Locations::new(0, 0);
+        assert_eq!(iter.next(), None);
+
+        let mut iter = Locations::new(1, 1);
+        assert_eq!(iter.next(), Some(Location { row: 0, column: 0 }));
+        assert_eq!(iter.next(), None);
+
+        let mut iter = Locations::new(2, 3);
+        assert_eq!(iter.next(), Some(Location { row: 0, column: 0 }));
+        assert_eq!(iter.next(), Some(Location { row: 0, column: 1 }));
+        assert_eq!(iter.next(), Some(Location { row: 0, column: 2 }));
+        assert_eq!(iter.next(), Some(Location { row: 1, column: 0 }));
+        assert_eq!(iter.next(), Some(Location { row: 1, column: 1 }));
+        assert_eq!(iter.next(), Some(Location { row: 1, column: 2 }));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_location_add() {
+        assert_eq!(
+            Location { row: 0, column: 0 } + Vector([1, 2]),
+            Location { row: 2, column: 1 }
+        );
+        assert_eq!(
+            Location { row: 2, column: 3 } + Vector([-1, -2]),
+            Location { row: 0, column: 2 }
+        );
+        assert_eq!(
+            Location { row: 0, column: 3 }.checked_add(Vector([0, -1])),
+            None
+        );
+    }
+
+    #[test]
+    fn test_location_neighbors() {
+        assert_eq!(
+            Location { row: 0, column: 0 }
+                .neighbors(Neighborhood::Four)
+                .collect::<Vec<Location>>(),
+            vec![
+                Location { row: 0, column: 1 },
+                Location { row: 1, column: 0 }
+            ]
+        );
+        assert_eq!(
+            Location { row: 1, column: 1 }
+                .neighbors(Neighborhood::Four)
+                .collect::<Vec<Location>>(),
+            vec![
+                Location { row: 0, column: 1 },
+                Location { row: 1, column: 2 },
+                Location { row: 2, column: 1 },
+                Location { row: 1, column: 0 },
+            ]
+        );
+        assert_eq!(
+            Location { row: 0, column: 0 }
+                .neighbors(Neighborhood::Eight)
+                .collect::<Vec<Location>>(),
+            vec![
+                Location { row: 0, column: 1 },
+                Location { row: 1, column: 1 },
+                Location { row: 1, column: 0 },
+            ]
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use crate::location::Location;
+use std::collections::hash_set;
+use std::collections::HashSet;
+use std::fmt;
+
+/// A grid that only stores the locations that are set, for grids that are large, mostly empty,
+/// or without fixed bounds. It extends from the origin to the furthest location that is set.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SparseGrid {
+    locations: HashSet<Location>,
+}
+
+impl SparseGrid {
+    pub fn insert(&mut self, location: Location) -> bool {
+        self.locations.insert(location)
+    }
+
+    pub fn remove(&mut self, location: &Location) -> bool {
+        self.locations.remove(location)
+    }
+
+    pub fn contains(&self, location: &Location) -> bool {
+        self.locations.contains(location)
+    }
+
+    pub fn len(&self) -> usize {
+        self.locations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.locations.is_empty()
+    }
+
+    pub fn iter(&self) -> hash_set::Iter<'_, Location> {
+        self.locations.iter()
+    }
+
+    /// The bottom-right corner of the smallest grid, anchored at the origin, that contains every
+    /// location that is set.
+    pub fn extents(&self) -> Option<Location> {
+        if self.locations.is_empty() {
+            return None;
+        }
+
+        let mut row = 0;
+        let mut column = 0;
+
+        for location in self.locations.iter() {
+            if location.row > row {
+                row = location.row;
+            }
+            if location.column > column {
+                column = location.column;
+            }
+        }
+
+        Some(Location { row, column })
+    }
+}
+
+impl FromIterator<Location> for SparseGrid {
+    fn from_iter<I: IntoIterator<Item = Location>>(iter: I) -> Self {
+        Self {
+            locations: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl IntoIterator for SparseGrid {
+    type Item = Location;
+    type IntoIter = hash_set::IntoIter<Location>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.locations.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a SparseGrid {
+    type Item = &'a Location;
+    type IntoIter = hash_set::Iter<'a, Location>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.locations.iter()
+    }
+}
+
+impl fmt::Display for SparseGrid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let extents = match self.extents() {
+            Some(extents) => extents,
+            None => return Ok(()),
+        };
+
+        for row in 0..=extents.row {
+            for column in 0..=extents.column {
+                if self.contains(&Location { row, column }) {
+                    write!(f, "#")?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparse_grid_extents() {
+        let grid: SparseGrid = Default::default();
+        assert_eq!(grid.extents(), None);
+
+        let grid: SparseGrid = [Location::new(3, 0), Location::new(1, 5)]
+            .into_iter()
+            .collect();
+        assert_eq!(grid.extents(), Some(Location::new(3, 5)));
+    }
+
+    #[test]
+    fn test_sparse_grid_display() {
+        let grid: SparseGrid = [Location::new(0, 0), Location::new(1, 2)]
+            .into_iter()
+            .collect();
+        assert_eq!(format!("{}", grid), "#..\n..#\n");
+    }
+}
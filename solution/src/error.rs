@@ -0,0 +1,163 @@
+use peg::error::ParseError as PegParseError;
+use peg::str::LineCol;
+use std::error::Error;
+use std::fmt;
+
+/// A failure to parse puzzle input, pointing at the place in the input where it went wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line of the input, starting at 1.
+    pub line: usize,
+    /// The character within the line, starting at 1.
+    pub column: usize,
+    /// What would have been accepted at that position, sorted.
+    pub expected: Vec<String>,
+    /// The offending line with a caret under the column, ready to be printed.
+    pub snippet: String,
+}
+
+impl ParseError {
+    /// An error at the byte `offset` of `input`, which may be the end of the input.
+    pub fn new<I, S>(input: &str, offset: usize, expected: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        let line = before.matches('\n').count() + 1;
+        let column = input[line_start..offset].chars().count() + 1;
+        let text = input[line_start..line_end].trim_end_matches('\r');
+
+        Self::build(line, column, text, expected)
+    }
+
+    /// An error at the byte `offset` of `text`, which is the given line of a larger input. This is
+    /// for parsers that read their input a line at a time, without ever holding all of it.
+    pub fn in_line<I, S>(line: usize, text: &str, offset: usize, expected: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let text = text.trim_end_matches(['\r', '\n']);
+        let column = text[..offset.min(text.len())].chars().count() + 1;
+
+        Self::build(line, column, text, expected)
+    }
+
+    fn build<I, S>(line: usize, column: usize, text: &str, expected: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut expected: Vec<String> = expected.into_iter().map(Into::into).collect();
+        expected.sort_unstable();
+        expected.dedup();
+
+        // Line it up the way rustc does, with the line number in a gutter on the left.
+        let gutter = " ".repeat(line.to_string().len());
+        let snippet = format!(
+            "{} |\n{} | {}\n{} | {}^",
+            gutter,
+            line,
+            text,
+            gutter,
+            " ".repeat(column - 1)
+        );
+
+        Self {
+            line,
+            column,
+            expected,
+            snippet,
+        }
+    }
+
+    /// An error at the start of `part`, which has to be a slice of `input`. This is convenient
+    /// for parsers that work by splitting the input into pieces.
+    pub fn at<I, S>(input: &str, part: &str, expected: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset + part.len() <= input.len())
+            .expect("part must be a slice of the input");
+
+        Self::new(input, offset, expected)
+    }
+
+    /// Converts an error from one of the `peg` grammars, which only knows about the position.
+    pub fn from_peg(input: &str, error: PegParseError<LineCol>) -> Self {
+        Self::new(input, error.location.offset, error.expected.tokens())
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "parse error at line {}, column {}: ",
+            self.line, self.column
+        )?;
+
+        match self.expected.as_slice() {
+            [] => write!(f, "unexpected input")?,
+            [token] => write!(f, "expected {}", token)?,
+            tokens => write!(f, "expected one of {}", tokens.join(", "))?,
+        }
+
+        write!(f, "\n{}", self.snippet)
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let input = "forward 5\ndown x\nup 3\n";
+        let error = ParseError::new(input, 15, ["\"0\"", "\"1\""]);
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 6);
+        assert_eq!(error.expected, vec!["\"0\"", "\"1\""]);
+        assert_eq!(error.snippet, "  |\n2 | down x\n  |      ^");
+        assert_eq!(
+            error.to_string(),
+            "parse error at line 2, column 6: expected one of \"0\", \"1\"\n  |\n2 | down x\n  |      ^"
+        );
+    }
+
+    #[test]
+    fn test_new_at_end() {
+        let error = ParseError::new("1\n2\n", 4, ["digit"]);
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(
+            error.to_string(),
+            "parse error at line 3, column 1: expected digit\n  |\n3 | \n  | ^"
+        );
+    }
+
+    #[test]
+    fn test_at() {
+        let input = "3,4,x,1";
+        let part = input.split(',').nth(2).unwrap();
+        let error = ParseError::at(input, part, ["number"]);
+        assert_eq!((error.line, error.column), (1, 5));
+    }
+
+    #[test]
+    fn test_in_line() {
+        let error = ParseError::in_line(12, "down x\n", 5, ["digit"]);
+        let input = "\n".repeat(11) + "down x\n";
+        assert_eq!(error, ParseError::new(&input, 16, ["digit"]));
+    }
+}
@@ -0,0 +1,58 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde_json::Value;
+
+/// Random puzzle input, along with anything about it that's known without solving it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Generated {
+    pub input: String,
+    /// What the input was built from, for puzzles where that's worth checking a solver against.
+    pub truth: Option<Value>,
+}
+
+impl Generated {
+    pub fn new(input: String) -> Self {
+        Self { input, truth: None }
+    }
+}
+
+/// A generator of random input for a day's puzzle, for stressing its solver at sizes other than
+/// the real input's.
+pub trait Generator {
+    /// A size that gives input about as big as the real input.
+    const DEFAULT_SIZE: usize;
+
+    /// Generates input of the given size, which means something different for each puzzle, like
+    /// the number of boards or the number of scanners.
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated;
+}
+
+/// Generates input from a seed. The same seed and size always give the same input.
+pub fn generate<G: Generator>(seed: u64, size: usize) -> Generated {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    G::generate(&mut rng, size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Digits;
+
+    impl Generator for Digits {
+        const DEFAULT_SIZE: usize = 8;
+
+        fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Generated {
+            let digits = (0..size).map(|_| rng.gen_range(0..10).to_string());
+            Generated::new(digits.collect())
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        let generated = generate::<Digits>(1, Digits::DEFAULT_SIZE);
+        assert_eq!(generated.input.len(), 8);
+        assert_eq!(generated, generate::<Digits>(1, 8));
+        assert_ne!(generated, generate::<Digits>(2, 8));
+    }
+}
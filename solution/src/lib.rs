@@ -0,0 +1,31 @@
+mod error;
+mod generator;
+
+pub use crate::error::ParseError;
+pub use crate::generator::{generate, Generated, Generator};
+use serde_json::Value;
+use std::fmt::Display;
+
+/// A solver for both parts of a day's puzzle.
+///
+/// The raw input is parsed once and the same parsed value is handed to each part, so the parts
+/// must not consume it. Parts that need to mutate their input are expected to work on a copy.
+pub trait Solution {
+    /// The parsed puzzle input, which is allowed to borrow from the raw input.
+    type Input<'a>;
+
+    type AnswerA: Display;
+    type AnswerB: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn solve_part_a(input: &Self::Input<'_>) -> Self::AnswerA;
+
+    fn solve_part_b(input: &Self::Input<'_>) -> Self::AnswerB;
+
+    /// Intermediate results that are worth reporting alongside the answers, for puzzles where the
+    /// answer alone doesn't say much. Most puzzles don't have any.
+    fn extras(_input: &Self::Input<'_>) -> Option<Value> {
+        None
+    }
+}